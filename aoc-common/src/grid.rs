@@ -0,0 +1,346 @@
+// Grids of cells, parsed from the character maps of the puzzles
+
+use std::{
+    collections::HashMap,
+    ops::{Index, IndexMut},
+};
+
+use crate::{char_at, Bounds, ParseError, Point};
+
+/// Cells laid out on a 2D grid
+pub trait Grid<T> {
+    /// Cell at `point`, `None` when there is none
+    fn get(&self, point: Point) -> Option<&T>;
+
+    /// Rectangle holding all the cells, `None` when there are none
+    fn bounds(&self) -> Option<Bounds>;
+
+    fn contains(&self, point: Point) -> bool {
+        self.get(point).is_some()
+    }
+
+    /// Draw the grid line by line, `draw` picks the character of each point of the bounds
+    fn render(&self, mut draw: impl FnMut(Point, Option<&T>) -> char) -> String
+    where
+        Self: Sized,
+    {
+        let mut text = String::new();
+        if let Some(bounds) = self.bounds() {
+            for point in bounds.points() {
+                text.push(draw(point, self.get(point)));
+                if point.x == bounds.max.x {
+                    text.push('\n');
+                }
+            }
+        }
+        text
+    }
+}
+
+/// Parse every character of `text`, `cell` returns `None` for the unexpected ones
+fn parse_cells<T>(
+    text: &str,
+    what: &str,
+    mut cell: impl FnMut(char) -> Option<T>,
+    mut add: impl FnMut(Point, T),
+) -> Result<(), ParseError> {
+    for (y, line) in text.lines().enumerate() {
+        for (x, (i, c)) in line.char_indices().enumerate() {
+            let value = cell(c).ok_or_else(|| {
+                ParseError::new(line, char_at(line, i), format!("expected {}", what))
+                    .within(text, line)
+            })?;
+            add(Point::new(x as isize, y as isize), value);
+        }
+    }
+    Ok(())
+}
+
+/// Rectangular grid storing every cell, with `(0, 0)` in the top left corner
+///
+/// ```
+/// use aoc_common::{DenseGrid, Grid, Point};
+///
+/// let grid = DenseGrid::parse("123\n456", "a digit", |c| c.to_digit(10)).unwrap();
+/// assert_eq!((grid.width(), grid.height()), (3, 2));
+/// assert_eq!(grid[Point::new(2, 1)], 6);
+/// assert_eq!(grid.get(Point::new(3, 0)), None);
+/// assert_eq!(grid.neighbours(Point::new(0, 0)).count(), 2);
+/// assert!(DenseGrid::parse("12\n3", "a digit", |c| c.to_digit(10)).is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DenseGrid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> DenseGrid<T> {
+    /// Grid of `width` by `height` cells set to `fill`
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        DenseGrid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Grid of `width` by `height` cells, `f` gives the cell of each point
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(Point) -> T) -> Self {
+        let cells = (0..width * height)
+            .map(|index| {
+                f(Point::new(
+                    (index % width) as isize,
+                    (index / width) as isize,
+                ))
+            })
+            .collect();
+        DenseGrid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Parse a non-empty rectangular map, `what` describes the cells for the errors
+    pub fn parse(
+        text: &str,
+        what: &str,
+        cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let width = text.lines().next().map_or(0, |line| line.chars().count());
+        if width == 0 {
+            return Err(ParseError::missing(text, format!("expected {}", what)));
+        }
+        if let Some(line) = text.lines().find(|line| line.chars().count() != width) {
+            return Err(ParseError::new(
+                text,
+                line,
+                format!("expected {} characters", width),
+            ));
+        }
+
+        let mut cells = Vec::with_capacity(text.len());
+        parse_cells(text, what, cell, |_, value| cells.push(value))?;
+        Ok(DenseGrid {
+            width,
+            height: cells.len() / width,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn index_of(&self, point: Point) -> Option<usize> {
+        let x = usize::try_from(point.x).ok().filter(|&x| x < self.width)?;
+        let y = usize::try_from(point.y).ok().filter(|&y| y < self.height)?;
+        Some(y * self.width + x)
+    }
+
+    fn point_of(&self, index: usize) -> Point {
+        Point::new((index % self.width) as isize, (index / self.width) as isize)
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        let index = self.index_of(point)?;
+        Some(&mut self.cells[index])
+    }
+
+    /// Cells of the line `y`
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    /// Cells of the column `x`, from top to bottom
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> + '_ {
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Every cell with its point, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> + '_ {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(index, cell)| (self.point_of(index), cell))
+    }
+
+    /// 4-connected neighbours of `point` that are on the grid
+    pub fn neighbours(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        point
+            .neighbours()
+            .filter(|&neighbour| self.index_of(neighbour).is_some())
+    }
+
+    /// First point, row by row, of a cell matching `predicate`
+    pub fn position(&self, predicate: impl FnMut(&T) -> bool) -> Option<Point> {
+        self.cells
+            .iter()
+            .position(predicate)
+            .map(|index| self.point_of(index))
+    }
+
+    /// Grid of the same size with `f` applied to every cell
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> DenseGrid<U> {
+        DenseGrid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> Grid<T> for DenseGrid<T> {
+    fn get(&self, point: Point) -> Option<&T> {
+        self.index_of(point).map(|index| &self.cells[index])
+    }
+
+    fn bounds(&self) -> Option<Bounds> {
+        (!self.cells.is_empty()).then(|| Bounds {
+            min: Point::ORIGIN,
+            max: Point::new(self.width as isize - 1, self.height as isize - 1),
+        })
+    }
+}
+
+impl<T> Index<Point> for DenseGrid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.get(point)
+            .unwrap_or_else(|| panic!("{} is out of the grid", point))
+    }
+}
+
+impl<T> IndexMut<Point> for DenseGrid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("{} is out of the grid", point))
+    }
+}
+
+/// Grid storing only the cells that are set, for maps without fixed borders
+///
+/// ```
+/// use aoc_common::{Grid, Point, SparseGrid};
+///
+/// let grid = SparseGrid::parse(".#\n#.", '.', "a wall", |c| (c == '#').then_some(())).unwrap();
+/// assert_eq!(grid.len(), 2);
+/// assert!(grid.contains(Point::new(1, 0)));
+/// assert!(!grid.contains(Point::new(0, 0)));
+/// assert_eq!(grid.render(|_, cell| if cell.is_some() { '#' } else { '.' }), ".#\n#.\n");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Point, T>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        SparseGrid {
+            cells: HashMap::new(),
+        }
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        SparseGrid::default()
+    }
+
+    /// Parse a map, the `empty` characters are left out of the grid
+    pub fn parse(
+        text: &str,
+        empty: char,
+        what: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let mut grid = SparseGrid::new();
+        parse_cells(
+            text,
+            what,
+            |c| {
+                if c == empty {
+                    Some(None)
+                } else {
+                    cell(c).map(Some)
+                }
+            },
+            |point, value| {
+                if let Some(value) = value {
+                    grid.insert(point, value);
+                }
+            },
+        )?;
+        Ok(grid)
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.cells.get_mut(&point)
+    }
+
+    /// Set the cell at `point`, returns the previous one
+    pub fn insert(&mut self, point: Point, cell: T) -> Option<T> {
+        self.cells.insert(point, cell)
+    }
+
+    pub fn remove(&mut self, point: Point) -> Option<T> {
+        self.cells.remove(&point)
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Every cell with its point, in no particular order
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> + '_ {
+        self.cells.iter().map(|(&point, cell)| (point, cell))
+    }
+
+    pub fn points(&self) -> impl Iterator<Item = Point> + '_ {
+        self.cells.keys().copied()
+    }
+}
+
+impl<T> Grid<T> for SparseGrid<T> {
+    fn get(&self, point: Point) -> Option<&T> {
+        self.cells.get(&point)
+    }
+
+    fn bounds(&self) -> Option<Bounds> {
+        Bounds::from_points(self.points())
+    }
+}
+
+impl<T> Index<Point> for SparseGrid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.get(point)
+            .unwrap_or_else(|| panic!("There is nothing at {}", point))
+    }
+}
+
+impl<T> FromIterator<(Point, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Point, T)>>(iter: I) -> Self {
+        SparseGrid {
+            cells: iter.into_iter().collect(),
+        }
+    }
+}
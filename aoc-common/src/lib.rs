@@ -0,0 +1,172 @@
+// Shared plumbing for the Advent of Code 2022 solutions
+
+use std::{
+    any::Any,
+    fmt::{self, Display},
+    marker::PhantomData,
+};
+
+pub use anyhow::{Error, Result};
+
+mod grid;
+mod parse;
+mod point;
+mod render;
+mod search;
+
+pub use grid::{DenseGrid, Grid, SparseGrid};
+pub use parse::{char_at, keyword, next_token, parse_lines, parse_token, ParseError};
+pub use point::{Bounds, Coordinate, ParsePointError, Point, Signed};
+pub use render::{hsl, Frame, Glyph, Render, Rgb, Simulation};
+pub use search::{astar, bfs, dijkstra, Bfs, Graph, Paths, WeightedGraph};
+
+/// One day of the Advent of Code
+///
+/// The input is parsed once and then handed, read only, to both parts. Parts
+/// that need to mutate the puzzle state work on their own copy.
+pub trait Solution {
+    /// Day number in the calendar, from 1 to 25
+    const DAY: u8;
+
+    /// Parsed representation of the puzzle input
+    type Input;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    /// Examples from the puzzle text, used to check the solution
+    const EXAMPLES: &'static [Example] = &[];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Result<Self::Answer1>;
+    fn part2(input: &Self::Input) -> Result<Self::Answer2>;
+
+    /// Simulation behind `part`, for the days that can be visualised
+    fn simulation(_input: &Self::Input, _part: Part) -> Option<Box<dyn Simulation>> {
+        None
+    }
+}
+
+/// Example given in the puzzle text, with its answers
+#[derive(Debug, Clone, Copy)]
+pub struct Example {
+    pub input: &'static str,
+    /// Expected answer of each part, `None` when the example does not cover it
+    pub part1: Option<&'static str>,
+    pub part2: Option<&'static str>,
+}
+
+impl Example {
+    pub fn answer(&self, part: Part) -> Option<&'static str> {
+        match part {
+            Part::One => self.part1,
+            Part::Two => self.part2,
+        }
+    }
+}
+
+/// Error returned by the parts that have not been solved (yet!)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Unsolved;
+
+impl Display for Unsolved {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("This part has not been solved")
+    }
+}
+
+impl std::error::Error for Unsolved {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl TryFrom<u8> for Part {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Self> {
+        match value {
+            1 => Ok(Part::One),
+            2 => Ok(Part::Two),
+            _ => Err(Error::msg(format!("There is no part {}", value))),
+        }
+    }
+}
+
+/// Type erased [`Solution`], allows to keep all the days in one list
+///
+/// The parsed input is passed around as `dyn Any` and only ever handed back to
+/// the puzzle that created it.
+pub trait Puzzle {
+    fn day(&self) -> u8;
+    fn examples(&self) -> &'static [Example];
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>>;
+    fn solve(&self, input: &dyn Any, part: Part) -> Result<String>;
+    fn simulation(&self, input: &dyn Any, part: Part) -> Result<Option<Box<dyn Simulation>>>;
+}
+
+struct Erased<S>(PhantomData<S>);
+
+impl<S> Puzzle for Erased<S>
+where
+    S: Solution,
+    S::Input: 'static,
+{
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        S::EXAMPLES
+    }
+
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>> {
+        let input = S::parse(input).map_err(|error| error.in_day(S::DAY))?;
+        Ok(Box::new(input))
+    }
+
+    fn solve(&self, input: &dyn Any, part: Part) -> Result<String> {
+        let input = Self::input(input)?;
+
+        match part {
+            Part::One => Ok(S::part1(input)?.to_string()),
+            Part::Two => Ok(S::part2(input)?.to_string()),
+        }
+    }
+
+    fn simulation(&self, input: &dyn Any, part: Part) -> Result<Option<Box<dyn Simulation>>> {
+        Ok(S::simulation(Self::input(input)?, part))
+    }
+}
+
+impl<S> Erased<S>
+where
+    S: Solution,
+    S::Input: 'static,
+{
+    fn input(input: &dyn Any) -> Result<&S::Input> {
+        input
+            .downcast_ref::<S::Input>()
+            .ok_or_else(|| Error::msg(format!("Input was not parsed by day {}", S::DAY)))
+    }
+}
+
+pub fn puzzle<S>() -> Box<dyn Puzzle>
+where
+    S: Solution + 'static,
+    S::Input: 'static,
+{
+    Box::new(Erased::<S>(PhantomData))
+}
@@ -0,0 +1,146 @@
+// Error reporting for the puzzle input parsers
+
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+/// Error found in a puzzle input, pointing at the offending token
+///
+/// The location is relative to the text that was being parsed. Parsers that
+/// hand a slice of their text to another parser move the error back into their
+/// own text with [`ParseError::within`], so that once it reaches the top the
+/// location is relative to the whole input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Day of the puzzle, known once the error leaves the day's parser
+    pub day: Option<u8>,
+    /// Line of the token, starting at 1
+    pub line: usize,
+    /// Column of the token, in characters, starting at 1
+    pub column: usize,
+    /// Offending token, empty when something is missing
+    pub token: String,
+    /// What was expected instead
+    pub message: String,
+}
+
+impl ParseError {
+    /// Error on `token`, which must be a slice of `text`
+    pub fn new(text: &str, token: &str, message: impl Into<String>) -> Self {
+        let (line, column) = location(text, token);
+        ParseError {
+            day: None,
+            line,
+            column,
+            token: token.to_owned(),
+            message: message.into(),
+        }
+    }
+
+    /// Error at the end of `text`, for when something is missing
+    pub fn missing(text: &str, message: impl Into<String>) -> Self {
+        ParseError::new(text, &text[text.len()..], message)
+    }
+
+    /// Relocate an error found in `inner`, a slice of `outer`, into `outer`
+    pub fn within(mut self, outer: &str, inner: &str) -> Self {
+        let (line, column) = location(outer, inner);
+        if self.line == 1 {
+            self.column += column - 1;
+        }
+        self.line += line - 1;
+        self
+    }
+
+    pub fn in_day(mut self, day: u8) -> Self {
+        self.day = Some(day);
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(day) = self.day {
+            write!(f, "Day {}, line {}", day, self.line)?;
+        } else {
+            write!(f, "Line {}", self.line)?;
+        }
+        write!(f, ", column {}: {}", self.column, self.message)?;
+        if !self.token.is_empty() {
+            write!(f, " (found `{}`)", self.token)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Line and column of `token` in `text`, `token` has to be a slice of `text`
+fn location(text: &str, token: &str) -> (usize, usize) {
+    let offset = (token.as_ptr() as usize).wrapping_sub(text.as_ptr() as usize);
+    assert!(
+        offset <= text.len(),
+        "The token has to be a slice of the parsed text"
+    );
+
+    let before = &text[..offset];
+    let line = before.matches('\n').count() + 1;
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    let column = before[line_start..].chars().count() + 1;
+    (line, column)
+}
+
+/// Parse `token`, a slice of `text`
+pub fn parse_token<T>(text: &str, token: &str) -> Result<T, ParseError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    token
+        .parse()
+        .map_err(|error: T::Err| ParseError::new(text, token, error.to_string()))
+}
+
+/// Next token of `text`, `what` describes the expected token if there is none
+pub fn next_token<'a>(
+    text: &str,
+    tokens: &mut impl Iterator<Item = &'a str>,
+    what: &str,
+) -> Result<&'a str, ParseError> {
+    tokens
+        .next()
+        .ok_or_else(|| ParseError::missing(text, format!("expected {}", what)))
+}
+
+/// Check that the next token of `text` is `keyword`
+pub fn keyword<'a>(
+    text: &str,
+    tokens: &mut impl Iterator<Item = &'a str>,
+    keyword: &str,
+) -> Result<(), ParseError> {
+    let what = format!("`{}`", keyword);
+    let token = next_token(text, tokens, &what)?;
+    if token == keyword {
+        Ok(())
+    } else {
+        Err(ParseError::new(text, token, format!("expected {}", what)))
+    }
+}
+
+/// Parse every line of `input`, errors are located in the whole input
+pub fn parse_lines<T>(
+    input: &str,
+    mut parse: impl FnMut(&str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    input
+        .lines()
+        .map(|line| parse(line).map_err(|error| error.within(input, line)))
+        .collect()
+}
+
+/// Character of `line` at byte `index`, as a slice to point errors at
+pub fn char_at(line: &str, index: usize) -> &str {
+    let len = line[index..].chars().next().map_or(0, char::len_utf8);
+    &line[index..index + len]
+}
@@ -0,0 +1,365 @@
+// Points on a 2D grid and the rectangles that hold them
+
+use std::{
+    fmt::{self, Debug, Display},
+    hash::Hash,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+    str::FromStr,
+};
+
+/// Integer type usable as a [`Point`] coordinate
+pub trait Coordinate:
+    Copy
+    + Debug
+    + Default
+    + Display
+    + Eq
+    + Ord
+    + Hash
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    /// `self + delta`, `None` when it does not fit in the type (ie. below 0 for `usize`)
+    fn offset(self, delta: isize) -> Option<Self>;
+
+    /// Distance between `self` and `other` on the number line
+    fn distance(self, other: Self) -> Self;
+}
+
+/// Coordinate that can go below 0, needed for rotations and unit directions
+pub trait Signed: Coordinate + Neg<Output = Self> {
+    const MINUS_ONE: Self;
+
+    fn signum(self) -> Self;
+}
+
+macro_rules! coordinate {
+    ($($t:ty),*) => {$(
+        impl Coordinate for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn offset(self, delta: isize) -> Option<Self> {
+                <$t>::try_from(delta).ok().and_then(|delta| self.checked_add(delta))
+            }
+
+            fn distance(self, other: Self) -> Self {
+                self.max(other) - self.min(other)
+            }
+        }
+
+        impl Signed for $t {
+            const MINUS_ONE: Self = -1;
+
+            fn signum(self) -> Self {
+                <$t>::signum(self)
+            }
+        }
+    )*};
+}
+
+coordinate!(i32, i64, isize);
+
+impl Coordinate for usize {
+    const ZERO: Self = 0;
+    const ONE: Self = 1;
+
+    fn offset(self, delta: isize) -> Option<Self> {
+        self.checked_add_signed(delta)
+    }
+
+    fn distance(self, other: Self) -> Self {
+        self.abs_diff(other)
+    }
+}
+
+/// Point of a grid, `x` grows to the right and `y` grows downwards as in the
+/// puzzle maps
+///
+/// Points are ordered row by row, then from left to right.
+///
+/// ```
+/// use aoc_common::Point;
+///
+/// let point = Point::new(1, 1);
+/// let around: Vec<_> = point.neighbours().collect();
+/// assert_eq!(
+///     around,
+///     [Point::new(1, 0), Point::new(0, 1), Point::new(2, 1), Point::new(1, 2)]
+/// );
+/// assert_eq!(Point::<usize>::new(0, 0).neighbours().count(), 2);
+/// assert_eq!(Point::<isize>::RIGHT.rotate_right(), Point::DOWN);
+/// assert_eq!(point.manhattan(Point::new(-2, 3)), 5);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Point<T = isize> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T: Coordinate> PartialOrd for Point<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Coordinate> Ord for Point<T> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        (self.y, self.x).cmp(&(other.y, other.x))
+    }
+}
+
+/// Offsets of the 4 neighbours, in reading order
+const NEIGHBOURS: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+
+/// Offsets of the 8 neighbours, in reading order
+const NEIGHBOURS8: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+impl<T: Coordinate> Point<T> {
+    pub const ORIGIN: Self = Point {
+        x: T::ZERO,
+        y: T::ZERO,
+    };
+
+    pub const fn new(x: T, y: T) -> Self {
+        Point { x, y }
+    }
+
+    /// Point at `(dx, dy)` from this one, `None` when it is out of the coordinate type
+    pub fn offset(self, dx: isize, dy: isize) -> Option<Self> {
+        Some(Point {
+            x: self.x.offset(dx)?,
+            y: self.y.offset(dy)?,
+        })
+    }
+
+    /// Up, left, right and down neighbours
+    pub fn neighbours(self) -> impl Iterator<Item = Self> {
+        NEIGHBOURS
+            .into_iter()
+            .filter_map(move |(dx, dy)| self.offset(dx, dy))
+    }
+
+    /// Neighbours including the diagonals
+    pub fn neighbours8(self) -> impl Iterator<Item = Self> {
+        NEIGHBOURS8
+            .into_iter()
+            .filter_map(move |(dx, dy)| self.offset(dx, dy))
+    }
+
+    pub fn manhattan(self, other: Self) -> T {
+        self.x.distance(other.x) + self.y.distance(other.y)
+    }
+
+    /// Distance when moving diagonally costs the same as moving straight
+    pub fn chebyshev(self, other: Self) -> T {
+        self.x.distance(other.x).max(self.y.distance(other.y))
+    }
+}
+
+impl<T: Signed> Point<T> {
+    pub const UP: Self = Point {
+        x: T::ZERO,
+        y: T::MINUS_ONE,
+    };
+    pub const DOWN: Self = Point {
+        x: T::ZERO,
+        y: T::ONE,
+    };
+    pub const LEFT: Self = Point {
+        x: T::MINUS_ONE,
+        y: T::ZERO,
+    };
+    pub const RIGHT: Self = Point {
+        x: T::ONE,
+        y: T::ZERO,
+    };
+
+    /// Quarter turn clockwise around the origin, on screen
+    pub fn rotate_right(self) -> Self {
+        Point {
+            x: -self.y,
+            y: self.x,
+        }
+    }
+
+    /// Quarter turn counterclockwise around the origin, on screen
+    pub fn rotate_left(self) -> Self {
+        Point {
+            x: self.y,
+            y: -self.x,
+        }
+    }
+
+    /// Unit step towards this point, diagonals included
+    pub fn signum(self) -> Self {
+        Point {
+            x: self.x.signum(),
+            y: self.y.signum(),
+        }
+    }
+}
+
+impl<T: Coordinate> Add for Point<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Point {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+        }
+    }
+}
+
+impl<T: Coordinate> Sub for Point<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Point {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+        }
+    }
+}
+
+impl<T: Coordinate> AddAssign for Point<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: Coordinate> SubAssign for Point<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<T: Coordinate> Mul<T> for Point<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self {
+        Point {
+            x: self.x * rhs,
+            y: self.y * rhs,
+        }
+    }
+}
+
+impl<T: Signed> Neg for Point<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Point {
+            x: -self.x,
+            y: -self.y,
+        }
+    }
+}
+
+impl<T: Display> Display for Point<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+/// Error of the `x,y` parser
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsePointError;
+
+impl Display for ParsePointError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("expected a point `x,y`")
+    }
+}
+
+impl std::error::Error for ParsePointError {}
+
+/// Parse `x,y`
+impl<T: FromStr> FromStr for Point<T> {
+    type Err = ParsePointError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (x, y) = s.split_once(',').ok_or(ParsePointError)?;
+        Ok(Point {
+            x: x.trim().parse().map_err(|_| ParsePointError)?,
+            y: y.trim().parse().map_err(|_| ParsePointError)?,
+        })
+    }
+}
+
+/// Smallest rectangle holding some points, both corners are included
+///
+/// ```
+/// use aoc_common::{Bounds, Point};
+///
+/// let bounds = Bounds::from_points([Point::new(2, 1), Point::new(-1, 3)]).unwrap();
+/// assert_eq!((bounds.width(), bounds.height()), (4, 3));
+/// assert!(bounds.contains(Point::new(0, 2)));
+/// assert_eq!(bounds.points().count(), 12);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Bounds<T = isize> {
+    pub min: Point<T>,
+    pub max: Point<T>,
+}
+
+impl<T: Coordinate> Bounds<T> {
+    /// Rectangle of a single point
+    pub fn new(point: Point<T>) -> Self {
+        Bounds {
+            min: point,
+            max: point,
+        }
+    }
+
+    /// `None` when there are no points
+    pub fn from_points(points: impl IntoIterator<Item = Point<T>>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let mut bounds = Bounds::new(points.next()?);
+        points.for_each(|point| bounds.extend(point));
+        Some(bounds)
+    }
+
+    /// Grow the rectangle to hold `point`
+    pub fn extend(&mut self, point: Point<T>) {
+        self.min = Point::new(self.min.x.min(point.x), self.min.y.min(point.y));
+        self.max = Point::new(self.max.x.max(point.x), self.max.y.max(point.y));
+    }
+
+    pub fn contains(&self, point: Point<T>) -> bool {
+        (self.min.x..=self.max.x).contains(&point.x) && (self.min.y..=self.max.y).contains(&point.y)
+    }
+
+    pub fn width(&self) -> T {
+        self.max.x - self.min.x + T::ONE
+    }
+
+    pub fn height(&self) -> T {
+        self.max.y - self.min.y + T::ONE
+    }
+
+    /// Every point of the rectangle, row by row
+    pub fn points(self) -> impl Iterator<Item = Point<T>> {
+        let step = |n: &T| n.offset(1);
+        std::iter::successors(Some(self.min.y), step)
+            .take_while(move |&y| y <= self.max.y)
+            .flat_map(move |y| {
+                std::iter::successors(Some(self.min.x), step)
+                    .take_while(move |&x| x <= self.max.x)
+                    .map(move |x| Point::new(x, y))
+            })
+    }
+}
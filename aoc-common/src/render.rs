@@ -0,0 +1,168 @@
+// Pictures of the simulations, for the terminal visualisation
+
+use std::fmt::{self, Display};
+
+use crate::{Bounds, DenseGrid, Grid, Point};
+
+/// Red, green and blue components of a colour
+pub type Rgb = [u8; 3];
+
+/// Colour from its hue, saturation and lightness, all between 0 and 1
+pub fn hsl(hue: f32, saturation: f32, lightness: f32) -> Rgb {
+    let chroma = (1.0 - (2.0 * lightness - 1.0).abs()) * saturation;
+    let sector = (hue.rem_euclid(1.0)) * 6.0;
+    let x = chroma * (1.0 - (sector % 2.0 - 1.0).abs());
+    let (r, g, b) = match sector as u8 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+    let m = lightness - chroma / 2.0;
+    let channel = |c: f32| ((c + m).clamp(0.0, 1.0) * 255.0).round() as u8;
+    [channel(r), channel(g), channel(b)]
+}
+
+/// One character of a frame, with its colour
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Glyph {
+    pub ch: char,
+    /// `None` keeps the colour of the terminal
+    pub color: Option<Rgb>,
+}
+
+impl Glyph {
+    pub const BLANK: Glyph = Glyph::new(' ');
+
+    pub const fn new(ch: char) -> Self {
+        Glyph { ch, color: None }
+    }
+
+    pub const fn colored(ch: char, color: Rgb) -> Self {
+        Glyph {
+            ch,
+            color: Some(color),
+        }
+    }
+}
+
+impl From<char> for Glyph {
+    fn from(ch: char) -> Self {
+        Glyph::new(ch)
+    }
+}
+
+/// Picture of the state of a simulation, with a caption saying where it is at
+///
+/// ```
+/// use aoc_common::{Bounds, Frame, Glyph, Point};
+///
+/// let bounds = Bounds::from_points([Point::new(-1, -1), Point::new(1, 1)]).unwrap();
+/// let frame = Frame::draw(bounds, |p| if p == Point::ORIGIN { 'o' } else { '.' }.into());
+/// assert_eq!(frame.to_string(), "...\n.o.\n...\n");
+///
+/// let cropped = frame.crop(Bounds::from_points([Point::new(1, 1), Point::new(5, 1)]).unwrap());
+/// assert_eq!(cropped.to_string(), "o.\n");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    pub glyphs: DenseGrid<Glyph>,
+    pub caption: String,
+}
+
+impl Frame {
+    /// Draw every point of `bounds`, which ends up in the top left corner of the frame
+    pub fn draw(bounds: Bounds, mut draw: impl FnMut(Point) -> Glyph) -> Self {
+        let glyphs = DenseGrid::from_fn(
+            bounds.width().max(0) as usize,
+            bounds.height().max(0) as usize,
+            |p| draw(bounds.min + p),
+        );
+        Frame {
+            glyphs,
+            caption: String::new(),
+        }
+    }
+
+    /// Frame of plain text, the short lines are padded with blanks
+    pub fn from_text(text: &str) -> Self {
+        let lines: Vec<Vec<char>> = text.lines().map(|line| line.chars().collect()).collect();
+        let width = lines.iter().map(Vec::len).max().unwrap_or(0);
+        let glyphs = DenseGrid::from_fn(width, lines.len(), |p| {
+            lines[p.y as usize]
+                .get(p.x as usize)
+                .map_or(Glyph::BLANK, |&ch| Glyph::new(ch))
+        });
+        Frame {
+            glyphs,
+            caption: String::new(),
+        }
+    }
+
+    pub fn with_caption(mut self, caption: impl Into<String>) -> Self {
+        self.caption = caption.into();
+        self
+    }
+
+    pub fn width(&self) -> usize {
+        self.glyphs.width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.glyphs.height()
+    }
+
+    /// Part of the frame seen through `viewport`, in frame coordinates
+    pub fn crop(&self, viewport: Bounds) -> Frame {
+        let Some(frame) = self.glyphs.bounds() else {
+            return self.clone();
+        };
+        let min = Point::new(viewport.min.x.max(0), viewport.min.y.max(0));
+        let max = Point::new(
+            viewport.max.x.min(frame.max.x),
+            viewport.max.y.min(frame.max.y),
+        );
+        Frame::draw(Bounds { min, max }, |p| self.glyphs[p]).with_caption(self.caption.clone())
+    }
+
+    /// Lines of the frame with the ANSI escape codes of the colours
+    pub fn to_ansi(&self) -> String {
+        let mut text = String::new();
+        for row in self.glyphs.rows() {
+            for glyph in row {
+                match glyph.color {
+                    Some([r, g, b]) => {
+                        text += &format!("\x1b[38;2;{};{};{}m{}\x1b[0m", r, g, b, glyph.ch)
+                    }
+                    None => text.push(glyph.ch),
+                }
+            }
+            text.push('\n');
+        }
+        text
+    }
+}
+
+/// Lines of the frame, without the colours
+impl Display for Frame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.glyphs.rows() {
+            let line: String = row.iter().map(|glyph| glyph.ch).collect();
+            writeln!(f, "{}", line)?;
+        }
+        Ok(())
+    }
+}
+
+/// State that can be drawn
+pub trait Render {
+    fn render(&self) -> Frame;
+}
+
+/// Simulation that the visualisation replays one step at a time
+pub trait Simulation: Render {
+    /// Advance by one step, returns `false` once the simulation is over
+    fn step(&mut self) -> bool;
+}
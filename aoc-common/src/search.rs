@@ -0,0 +1,280 @@
+// Shortest path searches: breadth first, Dijkstra and A*
+
+use std::{
+    cmp::Reverse,
+    collections::{hash_map::Entry, BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+};
+
+/// Graph where every step costs 1, given by the neighbours of its nodes
+///
+/// Implemented by the closures returning the neighbours of a node.
+pub trait Graph<N> {
+    type Neighbours: IntoIterator<Item = N>;
+
+    fn neighbours(&mut self, node: &N) -> Self::Neighbours;
+}
+
+impl<N, F, I> Graph<N> for F
+where
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    type Neighbours = I;
+
+    fn neighbours(&mut self, node: &N) -> I {
+        self(node)
+    }
+}
+
+/// Graph where every step has its own cost, given by the edges leaving its nodes
+///
+/// Implemented by the closures returning the `(neighbour, cost)` of a node.
+pub trait WeightedGraph<N> {
+    type Edges: IntoIterator<Item = (N, usize)>;
+
+    fn edges(&mut self, node: &N) -> Self::Edges;
+}
+
+impl<N, F, I> WeightedGraph<N> for F
+where
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, usize)>,
+{
+    type Edges = I;
+
+    fn edges(&mut self, node: &N) -> I {
+        self(node)
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Visit<N> {
+    cost: usize,
+    parent: Option<N>,
+    /// The cost cannot get any lower
+    done: bool,
+}
+
+/// Nodes reached by a search, with the cost and the best path to get there
+#[derive(Debug, Clone)]
+pub struct Paths<N> {
+    visits: HashMap<N, Visit<N>>,
+    /// Node that stopped the search, if any
+    pub goal: Option<N>,
+}
+
+impl<N: Clone + Eq + Hash> Paths<N> {
+    fn new(start: N) -> Self {
+        let mut visits = HashMap::new();
+        visits.insert(
+            start,
+            Visit {
+                cost: 0,
+                parent: None,
+                done: false,
+            },
+        );
+        Paths { visits, goal: None }
+    }
+
+    /// Cost from the start to `node`, `None` when it was not reached
+    pub fn cost(&self, node: &N) -> Option<usize> {
+        self.visits.get(node).map(|visit| visit.cost)
+    }
+
+    /// Nodes from the start to `node` included, `None` when it was not reached
+    pub fn path(&self, node: &N) -> Option<Vec<N>> {
+        let mut path = vec![node.clone()];
+        let mut visit = self.visits.get(node)?;
+        while let Some(parent) = &visit.parent {
+            path.push(parent.clone());
+            visit = &self.visits[parent];
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// Cost to the goal, `None` when the search did not find one
+    pub fn goal_cost(&self) -> Option<usize> {
+        self.goal.as_ref().and_then(|goal| self.cost(goal))
+    }
+
+    /// Every node reached, with its cost
+    pub fn iter(&self) -> impl Iterator<Item = (&N, usize)> + '_ {
+        self.visits.iter().map(|(node, visit)| (node, visit.cost))
+    }
+
+    pub fn len(&self) -> usize {
+        self.visits.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.visits.is_empty()
+    }
+}
+
+/// Breadth first search that can be run one node at a time
+///
+/// ```
+/// use aoc_common::Bfs;
+///
+/// // Count up to 10 by adding 1 or doubling
+/// let paths = Bfs::new(1).run(|&n: &u32| [n + 1, n * 2], |&n| n == 10);
+/// assert_eq!(paths.goal_cost(), Some(4));
+/// assert_eq!(paths.path(&10), Some(vec![1, 2, 4, 5, 10]));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Bfs<N> {
+    paths: Paths<N>,
+    queue: VecDeque<N>,
+}
+
+impl<N: Clone + Eq + Hash> Bfs<N> {
+    pub fn new(start: N) -> Self {
+        Bfs {
+            paths: Paths::new(start.clone()),
+            queue: VecDeque::from([start]),
+        }
+    }
+
+    /// Visit the next node and queue its neighbours, returns the visited node
+    /// or `None` once every reachable node has been visited
+    pub fn step(&mut self, graph: &mut impl Graph<N>) -> Option<N> {
+        let node = self.queue.pop_front()?;
+        let visit = self.paths.visits.get_mut(&node).unwrap();
+        visit.done = true;
+        let cost = visit.cost + 1;
+
+        for next in graph.neighbours(&node) {
+            if let Entry::Vacant(entry) = self.paths.visits.entry(next.clone()) {
+                entry.insert(Visit {
+                    cost,
+                    parent: Some(node.clone()),
+                    done: false,
+                });
+                self.queue.push_back(next);
+            }
+        }
+
+        Some(node)
+    }
+
+    /// Next node to be visited
+    pub fn peek(&self) -> Option<&N> {
+        self.queue.front()
+    }
+
+    /// Whether `node` has been visited, its neighbours are then reached too
+    pub fn is_visited(&self, node: &N) -> bool {
+        self.paths.visits.get(node).is_some_and(|visit| visit.done)
+    }
+
+    pub fn paths(&self) -> &Paths<N> {
+        &self.paths
+    }
+
+    /// Search until `goal` accepts a node or every reachable node is visited
+    pub fn run(mut self, mut graph: impl Graph<N>, mut goal: impl FnMut(&N) -> bool) -> Paths<N> {
+        while let Some(node) = self.peek() {
+            if goal(node) {
+                self.paths.goal = Some(node.clone());
+                break;
+            }
+            self.step(&mut graph);
+        }
+        self.paths
+    }
+}
+
+/// Breadth first search from `start` until `goal` accepts a node, `|_| false`
+/// searches the whole graph
+pub fn bfs<N: Clone + Eq + Hash>(
+    start: N,
+    graph: impl Graph<N>,
+    goal: impl FnMut(&N) -> bool,
+) -> Paths<N> {
+    Bfs::new(start).run(graph, goal)
+}
+
+/// Dijkstra search from `start` until `goal` accepts a node, `|_| false`
+/// searches the whole graph
+pub fn dijkstra<N: Clone + Eq + Hash + Ord>(
+    start: N,
+    graph: impl WeightedGraph<N>,
+    goal: impl FnMut(&N) -> bool,
+) -> Paths<N> {
+    astar(start, graph, |_| 0, goal)
+}
+
+/// A* search from `start` until `goal` accepts a node
+///
+/// The `heuristic` estimates the cost left to reach the goal. The path found
+/// is the shortest as long as it never overestimates it, nor decreases by more
+/// than the cost of a step.
+///
+/// ```
+/// use aoc_common::{astar, Point};
+///
+/// // Walk around a wall on an open plane, going up costs 2
+/// let wall = |p: &Point| p.x == 2 && p.y > -2;
+/// let target = Point::new(4, 0);
+/// let paths = astar(
+///     Point::ORIGIN,
+///     |p: &Point| {
+///         p.neighbours()
+///             .filter(|n| !wall(n))
+///             .map(|n| (n, if n.y < p.y { 2 } else { 1 }))
+///             .collect::<Vec<_>>()
+///     },
+///     |p| p.manhattan(target) as usize,
+///     |p| *p == target,
+/// );
+/// assert_eq!(paths.goal_cost(), Some(10));
+/// assert_eq!(paths.path(&target).unwrap().len(), 9);
+/// ```
+pub fn astar<N: Clone + Eq + Hash + Ord>(
+    start: N,
+    mut graph: impl WeightedGraph<N>,
+    mut heuristic: impl FnMut(&N) -> usize,
+    mut goal: impl FnMut(&N) -> bool,
+) -> Paths<N> {
+    let mut paths = Paths::new(start.clone());
+    let mut queue = BinaryHeap::new();
+    queue.push(Reverse((heuristic(&start), 0, start)));
+
+    while let Some(Reverse((_, cost, node))) = queue.pop() {
+        let visit = paths.visits.get_mut(&node).unwrap();
+        // Reached again with a lower cost since it was queued
+        if visit.done || visit.cost < cost {
+            continue;
+        }
+        visit.done = true;
+
+        if goal(&node) {
+            paths.goal = Some(node);
+            break;
+        }
+
+        for (next, step) in graph.edges(&node) {
+            let next_cost = cost + step;
+            let better = match paths.visits.get(&next) {
+                Some(visit) => !visit.done && next_cost < visit.cost,
+                None => true,
+            };
+            if better {
+                paths.visits.insert(
+                    next.clone(),
+                    Visit {
+                        cost: next_cost,
+                        parent: Some(node.clone()),
+                        done: false,
+                    },
+                );
+                queue.push(Reverse((next_cost + heuristic(&next), next_cost, next)));
+            }
+        }
+    }
+
+    paths
+}
@@ -0,0 +1,116 @@
+// Time the phases of the days and compare them with a saved baseline
+
+use std::{
+    fmt::{self, Display},
+    time::{Duration, Instant},
+};
+
+use anyhow::{Context, Result};
+use aoc_common::{Part, Puzzle, Unsolved};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Phase {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl From<Part> for Phase {
+    fn from(part: Part) -> Self {
+        match part {
+            Part::One => Phase::Part1,
+            Part::Two => Phase::Part2,
+        }
+    }
+}
+
+impl Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Phase::Parse => f.write_str("parse"),
+            Phase::Part1 => f.write_str("part 1"),
+            Phase::Part2 => f.write_str("part 2"),
+        }
+    }
+}
+
+/// Timings of one phase over all the runs, as saved in the baseline
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Timing {
+    pub day: u8,
+    pub phase: Phase,
+    pub runs: usize,
+    pub min_ms: f64,
+    pub median_ms: f64,
+    pub max_ms: f64,
+}
+
+impl Timing {
+    fn new(day: u8, phase: Phase, mut durations: Vec<Duration>) -> Self {
+        durations.sort();
+        let ms = |duration: Duration| duration.as_secs_f64() * 1000.0;
+
+        Timing {
+            day,
+            phase,
+            runs: durations.len(),
+            min_ms: ms(durations[0]),
+            median_ms: ms(durations[durations.len() / 2]),
+            max_ms: ms(durations[durations.len() - 1]),
+        }
+    }
+
+    /// Slowdown of the median compared to `baseline`, in percent
+    pub fn slowdown(&self, baseline: &Timing) -> f64 {
+        (self.median_ms / baseline.median_ms - 1.0) * 100.0
+    }
+}
+
+impl Display for Timing {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Day {} {}: min {:.3} ms, median {:.3} ms, max {:.3} ms",
+            self.day, self.phase, self.min_ms, self.median_ms, self.max_ms
+        )
+    }
+}
+
+/// Time `f` over `runs` runs, `runs` has to be at least 1
+fn repeat<T>(runs: usize, mut f: impl FnMut() -> Result<T>) -> Result<Vec<Duration>> {
+    (0..runs)
+        .map(|_| {
+            let start = Instant::now();
+            f()?;
+            Ok(start.elapsed())
+        })
+        .collect()
+}
+
+/// Time the parsing and the `parts` of `puzzle`, the parts that are not solved are skipped
+pub fn bench_puzzle(
+    puzzle: &dyn Puzzle,
+    input: &str,
+    parts: &[Part],
+    runs: usize,
+) -> Result<Vec<Timing>> {
+    let day = puzzle.day();
+    let mut timings = Vec::new();
+
+    let parse = repeat(runs, || puzzle.parse(input))
+        .with_context(|| format!("Day {}: cannot parse the input", day))?;
+    timings.push(Timing::new(day, Phase::Parse, parse));
+
+    let input = puzzle.parse(input)?;
+    for &part in parts {
+        match repeat(runs, || puzzle.solve(input.as_ref(), part)) {
+            Ok(durations) => timings.push(Timing::new(day, part.into(), durations)),
+            Err(error) if error.is::<Unsolved>() => (),
+            Err(error) => return Err(error.context(format!("Day {} part {}", day, part.number()))),
+        }
+    }
+
+    Ok(timings)
+}
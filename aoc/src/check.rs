@@ -0,0 +1,112 @@
+// Check the answers against the examples and the known answers of the inputs
+
+use std::{
+    fmt::{self, Display},
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::Result;
+use aoc_common::{Part, Puzzle, Unsolved};
+
+/// What was checked, for the report
+enum Subject {
+    Example(usize),
+    Input,
+}
+
+impl Display for Subject {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Subject::Example(n) => write!(f, "example {}", n),
+            Subject::Input => f.write_str("input"),
+        }
+    }
+}
+
+/// Tally of the checks
+#[derive(Default)]
+pub struct Summary {
+    pub passed: usize,
+    pub failed: usize,
+}
+
+impl Summary {
+    fn report(&mut self, day: u8, part: Part, subject: Subject, failure: Option<String>) {
+        match failure {
+            None => {
+                self.passed += 1;
+                println!("Day {} part {} {}: ok", day, part.number(), subject);
+            }
+            Some(failure) => {
+                self.failed += 1;
+                println!(
+                    "Day {} part {} {}: FAILED, {}",
+                    day,
+                    part.number(),
+                    subject,
+                    failure
+                );
+            }
+        }
+    }
+}
+
+/// File holding the known answer of `part` for `input`, ie. `input.1.expected`
+pub fn expected_path(input: &Path, part: Part) -> PathBuf {
+    let mut name = input.file_name().unwrap_or_default().to_owned();
+    name.push(format!(".{}.expected", part.number()));
+    input.with_file_name(name)
+}
+
+/// Solve `part` and compare with the expected answer, returns what went wrong
+fn verify(puzzle: &dyn Puzzle, input: &str, part: Part, expected: &str) -> Option<String> {
+    let input = match puzzle.parse(input) {
+        Ok(input) => input,
+        Err(error) => return Some(format!("{:#}", error)),
+    };
+
+    match puzzle.solve(input.as_ref(), part) {
+        Ok(answer) if answer.trim_end() == expected.trim_end() => None,
+        Ok(answer) => Some(format!(
+            "expected `{}`, got `{}`",
+            expected.trim_end(),
+            answer.trim_end()
+        )),
+        Err(error) if error.is::<Unsolved>() => Some("not solved".into()),
+        Err(error) => Some(format!("{:#}", error)),
+    }
+}
+
+/// Check the examples of `puzzle` and, when its answers are known, its input
+pub fn check_puzzle(
+    puzzle: &dyn Puzzle,
+    input: Option<&Path>,
+    parts: &[Part],
+    summary: &mut Summary,
+) -> Result<()> {
+    let day = puzzle.day();
+
+    for (n, example) in puzzle.examples().iter().enumerate() {
+        for &part in parts {
+            if let Some(expected) = example.answer(part) {
+                let failure = verify(puzzle, example.input, part, expected);
+                summary.report(day, part, Subject::Example(n + 1), failure);
+            }
+        }
+    }
+
+    if let Some(input) = input {
+        for &part in parts {
+            let expected = expected_path(input, part);
+            if expected.exists() {
+                let expected = fs::read_to_string(expected)?;
+                let input = fs::read_to_string(input)?;
+                let failure = verify(puzzle, &input, part, &expected);
+                summary.report(day, part, Subject::Input, failure);
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,37 @@
+// Registry of all the days known to the runner
+
+use aoc_common::{puzzle, Puzzle};
+
+pub fn all() -> Vec<Box<dyn Puzzle>> {
+    vec![
+        puzzle::<day1::Day1>(),
+        puzzle::<day2::Day2>(),
+        puzzle::<day3::Day3>(),
+        puzzle::<day4::Day4>(),
+        puzzle::<day5::Day5>(),
+        puzzle::<day6::Day6>(),
+        puzzle::<day7::Day7>(),
+        puzzle::<day8::Day8>(),
+        puzzle::<day9::Day9>(),
+        puzzle::<day10::Day10>(),
+        puzzle::<day11::Day11>(),
+        puzzle::<day12::Day12>(),
+        puzzle::<day13::Day13>(),
+        puzzle::<day14::Day14>(),
+        puzzle::<day15::Day15>(),
+        puzzle::<day16::Day16>(),
+        puzzle::<day17::Day17>(),
+        puzzle::<day18::Day18>(),
+        puzzle::<day19::Day19>(),
+        puzzle::<day20::Day20>(),
+        puzzle::<day21::Day21>(),
+        puzzle::<day22::Day22>(),
+        puzzle::<day23::Day23>(),
+        puzzle::<day24::Day24>(),
+        puzzle::<day25::Day25>(),
+    ]
+}
+
+pub fn get(day: u8) -> Option<Box<dyn Puzzle>> {
+    all().into_iter().find(|puzzle| puzzle.day() == day)
+}
@@ -0,0 +1,303 @@
+// Export the simulations of the days as animated images
+
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+    fs::File,
+    io::{BufWriter, Write},
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, Error, Result};
+use aoc_common::{Bounds, Frame, Glyph, Rgb, Simulation};
+
+const BACKGROUND: Rgb = [16, 16, 16];
+/// Colour of the `.` without a colour, usually the empty cells
+const DIM: Rgb = [64, 64, 64];
+/// Colour of the other characters without a colour
+const FOREGROUND: Rgb = [208, 208, 208];
+
+/// How the simulation is exported
+pub struct Options {
+    /// Frames per second of the animation
+    pub fps: f64,
+    /// Side of the square of pixels drawn for each character
+    pub scale: usize,
+    /// Keep one step out of `every`, the last one is always kept
+    pub every: usize,
+    /// Part of the frames to export, the whole frames when `None`
+    pub viewport: Option<Bounds>,
+}
+
+/// Animated image formats, picked from the extension of the file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Gif,
+    Apng,
+}
+
+impl Format {
+    fn from_path(path: &Path) -> Result<Self> {
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("gif") => Ok(Format::Gif),
+            Some("png" | "apng") => Ok(Format::Apng),
+            _ => Err(Error::msg(format!(
+                "Cannot export to {}, expected a .gif, .png or .apng file",
+                path.display()
+            ))),
+        }
+    }
+}
+
+fn color(glyph: &Glyph) -> Rgb {
+    glyph.color.unwrap_or(match glyph.ch {
+        ' ' => BACKGROUND,
+        '.' => DIM,
+        _ => FOREGROUND,
+    })
+}
+
+/// Render the steps kept by `options`, then the final state if it was skipped
+fn frames(
+    simulation: &mut dyn Simulation,
+    options: &Options,
+    mut f: impl FnMut(Frame) -> Result<()>,
+) -> Result<()> {
+    let mut render = |simulation: &dyn Simulation| {
+        let frame = simulation.render();
+        f(match options.viewport {
+            Some(viewport) => frame.crop(viewport),
+            None => frame,
+        })
+    };
+
+    let mut rendered = 0;
+    for step in 0.. {
+        if step % options.every == 0 {
+            render(&*simulation)?;
+            rendered = step;
+        }
+        if !simulation.step() {
+            if rendered != step {
+                render(&*simulation)?;
+            }
+            break;
+        }
+    }
+
+    Ok(())
+}
+
+/// Size and colours of all the frames, to set up the encoders
+struct Survey {
+    width: usize,
+    height: usize,
+    count: u32,
+    /// Every colour used, `None` when there are too many for a GIF palette
+    palette: Option<Vec<Rgb>>,
+    last: Frame,
+}
+
+fn survey(simulation: &mut dyn Simulation, options: &Options) -> Result<Survey> {
+    let mut palette = vec![BACKGROUND];
+    let mut too_many = false;
+    let mut survey = Survey {
+        width: 0,
+        height: 0,
+        count: 0,
+        palette: None,
+        last: Frame::from_text(""),
+    };
+
+    frames(simulation, options, |frame| {
+        survey.width = survey.width.max(frame.width());
+        survey.height = survey.height.max(frame.height());
+        survey.count += 1;
+
+        for (_, glyph) in frame.glyphs.iter() {
+            let color = color(glyph);
+            if !too_many && !palette.contains(&color) {
+                palette.push(color);
+                too_many = palette.len() > 256;
+            }
+        }
+
+        survey.last = frame;
+        Ok(())
+    })?;
+
+    survey.palette = (!too_many).then_some(palette);
+    Ok(survey)
+}
+
+/// RGB pixels of the canvas with `frame` in the top left corner
+fn rasterize(frame: &Frame, width: usize, height: usize, scale: usize) -> Vec<u8> {
+    let mut pixels = Vec::with_capacity(width * height * 3);
+    for y in 0..height {
+        let row = (y / scale < frame.height()).then(|| frame.glyphs.row(y / scale));
+        for x in 0..width {
+            let glyph = row.and_then(|row| row.get(x / scale));
+            pixels.extend(glyph.map_or(BACKGROUND, color));
+        }
+    }
+    pixels
+}
+
+fn write_gif(
+    simulation: &mut dyn Simulation,
+    options: &Options,
+    survey: &Survey,
+    (width, height): (usize, usize),
+    file: impl Write,
+) -> Result<()> {
+    let too_large = || Error::msg("The frames are too large for a GIF");
+    let (width, height) = (
+        u16::try_from(width).map_err(|_| too_large())?,
+        u16::try_from(height).map_err(|_| too_large())?,
+    );
+
+    // Frames are encoded with one shared palette when the colours fit in one
+    let global: Vec<u8> = survey.palette.iter().flatten().flatten().copied().collect();
+    let indices: HashMap<Rgb, u8> = survey
+        .palette
+        .iter()
+        .flatten()
+        .enumerate()
+        .map(|(index, &color)| (color, index as u8))
+        .collect();
+
+    let mut encoder = gif::Encoder::new(file, width, height, &global)?;
+    encoder.set_repeat(gif::Repeat::Infinite)?;
+    let delay = (100.0 / options.fps).round().clamp(1.0, u16::MAX as f64) as u16;
+
+    frames(simulation, options, |frame| {
+        let pixels = rasterize(&frame, width as usize, height as usize, options.scale);
+        let mut frame = match survey.palette {
+            Some(_) => gif::Frame {
+                width,
+                height,
+                buffer: Cow::Owned(
+                    pixels
+                        .chunks(3)
+                        .map(|rgb| indices[&[rgb[0], rgb[1], rgb[2]]])
+                        .collect(),
+                ),
+                ..Default::default()
+            },
+            None => gif::Frame::from_rgb_speed(width, height, &pixels, 10),
+        };
+        frame.delay = delay;
+        encoder.write_frame(&frame)?;
+        Ok(())
+    })
+}
+
+fn write_apng(
+    simulation: &mut dyn Simulation,
+    options: &Options,
+    survey: &Survey,
+    (width, height): (usize, usize),
+    file: impl Write,
+) -> Result<()> {
+    let too_large = || Error::msg("The frames are too large for a PNG");
+    let mut encoder = png::Encoder::new(
+        file,
+        u32::try_from(width).map_err(|_| too_large())?,
+        u32::try_from(height).map_err(|_| too_large())?,
+    );
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    encoder.set_animated(survey.count, 0)?;
+    let delay = (1000.0 / options.fps).round().clamp(1.0, u16::MAX as f64) as u16;
+    encoder.set_frame_delay(delay, 1000)?;
+
+    let mut writer = encoder.write_header()?;
+    frames(simulation, options, |frame| {
+        writer.write_image_data(&rasterize(&frame, width, height, options.scale))?;
+        Ok(())
+    })?;
+    writer.finish()?;
+    Ok(())
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// Frame as an SVG picture, each row is drawn as runs of the same colour
+fn to_svg(frame: &Frame, scale: usize) -> String {
+    let (width, height) = (frame.width(), frame.height());
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" \
+         viewBox=\"0 0 {} {}\" shape-rendering=\"crispEdges\">\n",
+        width * scale,
+        height * scale,
+        width,
+        height
+    );
+    if !frame.caption.is_empty() {
+        svg += &format!("<title>{}</title>\n", escape(&frame.caption));
+    }
+    let [r, g, b] = BACKGROUND;
+    svg += &format!(
+        "<rect width=\"{}\" height=\"{}\" fill=\"#{:02x}{:02x}{:02x}\"/>\n",
+        width, height, r, g, b
+    );
+
+    for (y, row) in frame.glyphs.rows().enumerate() {
+        let mut x = 0;
+        for run in row.chunk_by(|a, b| color(a) == color(b)) {
+            let [r, g, b] = color(&run[0]);
+            if [r, g, b] != BACKGROUND {
+                svg += &format!(
+                    "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"1\" fill=\"#{:02x}{:02x}{:02x}\"/>\n",
+                    x,
+                    y,
+                    run.len(),
+                    r,
+                    g,
+                    b
+                );
+            }
+            x += run.len();
+        }
+    }
+
+    svg += "</svg>\n";
+    svg
+}
+
+/// Export the simulations made by `simulation` to `path`, with the final state
+/// next to it as an SVG picture, returns the path of the picture
+///
+/// The simulation is run twice: once to size the image, once to encode it.
+pub fn export(
+    mut simulation: impl FnMut() -> Result<Box<dyn Simulation>>,
+    path: &Path,
+    options: &Options,
+) -> Result<PathBuf> {
+    let format = Format::from_path(path)?;
+    let survey = survey(simulation()?.as_mut(), options)?;
+    let size = (survey.width * options.scale, survey.height * options.scale);
+    if size.0 == 0 || size.1 == 0 {
+        return Err(Error::msg("The simulation has nothing to draw"));
+    }
+
+    let create = |path: &Path| {
+        File::create(path)
+            .map(BufWriter::new)
+            .with_context(|| format!("Cannot create {}", path.display()))
+    };
+
+    let file = create(path)?;
+    match format {
+        Format::Gif => write_gif(simulation()?.as_mut(), options, &survey, size, file)?,
+        Format::Apng => write_apng(simulation()?.as_mut(), options, &survey, size, file)?,
+    }
+
+    let svg_path = path.with_extension("svg");
+    create(&svg_path)?.write_all(to_svg(&survey.last, options.scale).as_bytes())?;
+    Ok(svg_path)
+}
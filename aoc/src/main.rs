@@ -0,0 +1,407 @@
+// Run any of the days from one place
+
+mod bench;
+mod check;
+mod days;
+mod export;
+mod visualize;
+
+use std::{
+    fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+    process::ExitCode,
+    time::Instant,
+};
+
+use anyhow::{Context, Error, Result};
+use aoc_common::{Bounds, Part, Puzzle, Unsolved};
+use bench::{bench_puzzle, Timing};
+use check::{check_puzzle, Summary};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use serde::Serialize;
+use visualize::{parse_fps, parse_viewport};
+
+#[derive(Parser)]
+#[command(about = "Advent of Code 2022 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve the puzzles
+    Run(RunArgs),
+    /// Check the answers of the examples, and of the inputs with a `dayN/input.P.expected` file
+    Check(CheckArgs),
+    /// Time the parsing and the parts, optionally against a saved baseline
+    Bench(BenchArgs),
+}
+
+/// Days and parts to work on
+#[derive(Args)]
+struct Selection {
+    /// Day to run, every day is run when omitted
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: Option<u8>,
+
+    /// Part to run, both parts are run when omitted
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+}
+
+impl Selection {
+    fn parts(&self) -> Result<Vec<Part>> {
+        match self.part {
+            Some(part) => Ok(vec![Part::try_from(part)?]),
+            None => Ok(Part::ALL.to_vec()),
+        }
+    }
+
+    fn puzzles(&self) -> Result<Vec<Box<dyn Puzzle>>> {
+        match self.day {
+            Some(day) => {
+                Ok(vec![days::get(day).ok_or_else(|| {
+                    Error::msg(format!("Day {} is not solved", day))
+                })?])
+            }
+            None => Ok(days::all()),
+        }
+    }
+}
+
+fn default_input(puzzle: &dyn Puzzle) -> PathBuf {
+    PathBuf::from(format!("day{}/input", puzzle.day()))
+}
+
+#[derive(Args)]
+struct RunArgs {
+    #[command(flatten)]
+    selection: Selection,
+
+    /// Puzzle input, `-` reads it from stdin [default: dayN/input]
+    #[arg(short, long, requires = "day")]
+    input: Option<PathBuf>,
+
+    /// How the answers are printed
+    #[arg(short, long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+
+    /// Replay the simulation of the parts in the terminal instead of printing the answers
+    #[arg(long, requires = "day")]
+    visualize: bool,
+
+    /// Export the simulation of the parts to an animated .gif or .png, with the
+    /// final state as an .svg next to it
+    #[arg(long, requires = "day", conflicts_with = "visualize")]
+    export: Option<PathBuf>,
+
+    /// Frames per second of the visualisation or of the export
+    #[arg(long, default_value_t = 10.0, value_parser = parse_fps)]
+    fps: f64,
+
+    /// Start the visualisation paused
+    #[arg(long, requires = "visualize")]
+    paused: bool,
+
+    /// Part of the frames to show, as `X,Y,WIDTH,HEIGHT` [default: as much as fits]
+    #[arg(long, value_parser = parse_viewport)]
+    viewport: Option<Bounds>,
+
+    /// Pixels per character of the exported images
+    #[arg(long, default_value_t = 4, value_parser = clap::value_parser!(u16).range(1..=64))]
+    scale: u16,
+
+    /// Export one step out of N, the final state is always exported
+    #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u64).range(1..))]
+    every: u64,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    /// One sentence per answer
+    Text,
+    /// One JSON record per line, for the machines
+    Json,
+}
+
+/// Answer of one part, as printed in JSON
+#[derive(Serialize)]
+struct Record {
+    day: u8,
+    part: u8,
+    /// `null` when the part is not solved
+    answer: Option<String>,
+    elapsed_ms: f64,
+}
+
+impl Record {
+    fn print(&self, format: Format) -> Result<()> {
+        match format {
+            Format::Text => match &self.answer {
+                Some(answer) if answer.contains('\n') => {
+                    println!("Day {} part {}:\n{}", self.day, self.part, answer)
+                }
+                Some(answer) => println!("Day {} part {}: {}", self.day, self.part, answer),
+                None => println!("Day {} part {}: not solved", self.day, self.part),
+            },
+            Format::Json => println!("{}", serde_json::to_string(self)?),
+        }
+
+        Ok(())
+    }
+}
+
+fn read_input(path: &Path) -> Result<String> {
+    if path == Path::new("-") {
+        let mut input = String::new();
+        io::stdin()
+            .read_to_string(&mut input)
+            .context("Cannot read the input from stdin")?;
+        Ok(input)
+    } else {
+        fs::read_to_string(path)
+            .with_context(|| format!("Cannot read the input file {}", path.display()))
+    }
+}
+
+fn run_puzzle(puzzle: &dyn Puzzle, input: &Path, parts: &[Part], format: Format) -> Result<()> {
+    let day = puzzle.day();
+    let input = read_input(input).with_context(|| format!("Day {}", day))?;
+    let input = puzzle.parse(&input).context("Cannot parse the input")?;
+
+    for &part in parts {
+        let start = Instant::now();
+        let answer = match puzzle.solve(input.as_ref(), part) {
+            Ok(answer) => Some(answer),
+            Err(error) if error.is::<Unsolved>() => None,
+            Err(error) => return Err(error.context(format!("Day {} part {}", day, part.number()))),
+        };
+
+        Record {
+            day,
+            part: part.number(),
+            answer,
+            elapsed_ms: start.elapsed().as_secs_f64() * 1000.0,
+        }
+        .print(format)?;
+    }
+
+    Ok(())
+}
+
+fn visualize_puzzle(
+    puzzle: &dyn Puzzle,
+    input: &Path,
+    parts: &[Part],
+    options: &visualize::Options,
+) -> Result<()> {
+    let day = puzzle.day();
+    let input = read_input(input).with_context(|| format!("Day {}", day))?;
+    let input = puzzle.parse(&input).context("Cannot parse the input")?;
+
+    for &part in parts {
+        let title = format!("Day {} part {}", day, part.number());
+        let mut simulation = puzzle
+            .simulation(input.as_ref(), part)?
+            .ok_or_else(|| Error::msg(format!("{} cannot be visualised", title)))?;
+
+        visualize::play(simulation.as_mut(), &title, options)?;
+    }
+
+    Ok(())
+}
+
+/// Path of the export of `part`, the part is added to the name when exporting both
+fn export_path(path: &Path, part: Part, parts: &[Part]) -> PathBuf {
+    if parts.len() == 1 {
+        return path.to_path_buf();
+    }
+    let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+    let mut name = format!("{}-part{}", stem, part.number());
+    if let Some(extension) = path.extension() {
+        name = format!("{}.{}", name, extension.to_string_lossy());
+    }
+    path.with_file_name(name)
+}
+
+fn export_puzzle(
+    puzzle: &dyn Puzzle,
+    input: &Path,
+    parts: &[Part],
+    path: &Path,
+    options: &export::Options,
+) -> Result<()> {
+    let day = puzzle.day();
+    let input = read_input(input).with_context(|| format!("Day {}", day))?;
+    let input = puzzle.parse(&input).context("Cannot parse the input")?;
+
+    for &part in parts {
+        let title = format!("Day {} part {}", day, part.number());
+        let simulation = || {
+            puzzle
+                .simulation(input.as_ref(), part)?
+                .ok_or_else(|| Error::msg("There is no simulation to export"))
+        };
+
+        let path = export_path(path, part, parts);
+        let svg = export::export(simulation, &path, options)
+            .with_context(|| format!("Cannot export {}", title))?;
+        eprintln!(
+            "{}: exported {} and {}",
+            title,
+            path.display(),
+            svg.display()
+        );
+    }
+
+    Ok(())
+}
+
+fn run(args: RunArgs) -> Result<()> {
+    let parts = args.selection.parts()?;
+    let options = visualize::Options {
+        fps: args.fps,
+        paused: args.paused,
+        viewport: args.viewport,
+    };
+    let export_options = export::Options {
+        fps: args.fps,
+        scale: args.scale.into(),
+        every: args.every as usize,
+        viewport: args.viewport,
+    };
+
+    for puzzle in args.selection.puzzles()? {
+        let input = args
+            .input
+            .clone()
+            .unwrap_or_else(|| default_input(puzzle.as_ref()));
+
+        if args.visualize {
+            visualize_puzzle(puzzle.as_ref(), &input, &parts, &options)?;
+        } else if let Some(path) = &args.export {
+            export_puzzle(puzzle.as_ref(), &input, &parts, path, &export_options)?;
+        } else {
+            run_puzzle(puzzle.as_ref(), &input, &parts, args.format)?;
+        }
+    }
+
+    Ok(())
+}
+
+#[derive(Args)]
+struct CheckArgs {
+    #[command(flatten)]
+    selection: Selection,
+
+    /// Only check the examples, the inputs can take a while
+    #[arg(short, long)]
+    examples: bool,
+}
+
+fn check(args: CheckArgs) -> Result<()> {
+    let parts = args.selection.parts()?;
+    let mut summary = Summary::default();
+
+    for puzzle in args.selection.puzzles()? {
+        let input = default_input(puzzle.as_ref());
+        let input = (!args.examples).then_some(input.as_path());
+
+        check_puzzle(puzzle.as_ref(), input, &parts, &mut summary)?;
+    }
+
+    println!("{} passed, {} failed", summary.passed, summary.failed);
+    if summary.failed > 0 {
+        return Err(Error::msg(format!("{} checks failed", summary.failed)));
+    }
+
+    Ok(())
+}
+
+#[derive(Args)]
+struct BenchArgs {
+    #[command(flatten)]
+    selection: Selection,
+
+    /// Number of times each phase is run
+    #[arg(short = 'n', long, default_value_t = 10, value_parser = clap::value_parser!(u64).range(1..))]
+    runs: u64,
+
+    /// Save the timings as a JSON baseline
+    #[arg(short, long)]
+    save: Option<PathBuf>,
+
+    /// Compare the timings with a baseline saved earlier
+    #[arg(short, long)]
+    baseline: Option<PathBuf>,
+
+    /// Slowdown of the median, in percent, above which a phase has regressed
+    #[arg(short, long, default_value_t = 10.0)]
+    threshold: f64,
+}
+
+fn bench(args: BenchArgs) -> Result<()> {
+    let parts = args.selection.parts()?;
+    let baseline: Vec<Timing> = match &args.baseline {
+        Some(path) => serde_json::from_str(&read_input(path)?)
+            .with_context(|| format!("Invalid baseline {}", path.display()))?,
+        None => Vec::new(),
+    };
+
+    let mut timings = Vec::new();
+    let mut regressions = 0;
+    for puzzle in args.selection.puzzles()? {
+        let input = read_input(&default_input(puzzle.as_ref()))?;
+
+        for timing in bench_puzzle(puzzle.as_ref(), &input, &parts, args.runs as usize)? {
+            let reference = baseline
+                .iter()
+                .find(|reference| reference.day == timing.day && reference.phase == timing.phase);
+
+            match reference.map(|reference| timing.slowdown(reference)) {
+                Some(slowdown) if slowdown > args.threshold => {
+                    regressions += 1;
+                    println!("{} (REGRESSION {:+.1}%)", timing, slowdown);
+                }
+                Some(slowdown) => println!("{} ({:+.1}%)", timing, slowdown),
+                None => println!("{}", timing),
+            }
+
+            timings.push(timing);
+        }
+    }
+
+    if let Some(path) = &args.save {
+        fs::write(path, serde_json::to_string_pretty(&timings)?)
+            .with_context(|| format!("Cannot save the baseline {}", path.display()))?;
+    }
+
+    if regressions > 0 {
+        return Err(Error::msg(format!(
+            "{} phases are more than {}% slower than the baseline",
+            regressions, args.threshold
+        )));
+    }
+
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let result = match cli.command {
+        Command::Run(args) => run(args),
+        Command::Check(args) => check(args),
+        Command::Bench(args) => bench(args),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("Error: {:#}", error);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,243 @@
+// Replay the simulations of the days in the terminal
+
+use std::{
+    io::{self, IsTerminal, Write},
+    thread,
+    time::Duration,
+};
+
+use anyhow::{Error, Result};
+use aoc_common::{Bounds, Frame, Point, Simulation};
+use crossterm::{
+    cursor,
+    event::{self, Event, KeyCode, KeyEvent, KeyModifiers},
+    execute, queue,
+    terminal::{self, ClearType},
+};
+
+const MAX_FPS: f64 = 1000.0;
+const MIN_FPS: f64 = 0.25;
+
+const HELP: &str = "space: pause, n: step, +/-: speed, arrows: scroll, q: quit";
+
+/// How the simulation is played
+pub struct Options {
+    /// Frames per second
+    pub fps: f64,
+    /// Wait for a key before the first step
+    pub paused: bool,
+    /// Part of the frames to show, as much as fits in the terminal when `None`
+    pub viewport: Option<Bounds>,
+}
+
+/// Parse a number of frames per second, which must be finite and positive
+pub fn parse_fps(text: &str) -> Result<f64> {
+    let fps: f64 = text.trim().parse().map_err(Error::msg)?;
+    if fps.is_finite() && fps > 0.0 {
+        Ok(fps)
+    } else {
+        Err(Error::msg(
+            "expected a positive number of frames per second",
+        ))
+    }
+}
+
+/// Parse a viewport given as `X,Y,WIDTH,HEIGHT`
+pub fn parse_viewport(text: &str) -> Result<Bounds> {
+    let numbers = text
+        .split(',')
+        .map(|n| n.trim().parse::<isize>())
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(Error::msg)?;
+
+    match numbers[..] {
+        [x, y, width, height] if width > 0 && height > 0 => Ok(Bounds {
+            min: Point::new(x, y),
+            max: Point::new(x + width - 1, y + height - 1),
+        }),
+        _ => Err(Error::msg("expected X,Y,WIDTH,HEIGHT with a positive size")),
+    }
+}
+
+/// What the viewer asked for
+enum Command {
+    Next,
+    Pause,
+    Step,
+    Speed(f64),
+    Scroll(isize, isize),
+    Quit,
+}
+
+/// Puts the terminal back as it was, even when the simulation panics
+struct Screen;
+
+impl Screen {
+    fn enter() -> Result<Self> {
+        terminal::enable_raw_mode()?;
+        execute!(io::stdout(), terminal::EnterAlternateScreen, cursor::Hide)?;
+        Ok(Screen)
+    }
+}
+
+impl Drop for Screen {
+    fn drop(&mut self) {
+        let _ = execute!(io::stdout(), cursor::Show, terminal::LeaveAlternateScreen);
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+/// Wait for a key for at most `timeout`, forever when `None`
+fn next_command(timeout: Option<Duration>) -> Result<Command> {
+    if let Some(timeout) = timeout {
+        if !event::poll(timeout)? {
+            return Ok(Command::Next);
+        }
+    }
+
+    let Event::Key(KeyEvent {
+        code, modifiers, ..
+    }) = event::read()?
+    else {
+        return Ok(Command::Scroll(0, 0));
+    };
+
+    let page = if modifiers.contains(KeyModifiers::SHIFT) {
+        10
+    } else {
+        1
+    };
+    Ok(match code {
+        KeyCode::Char('q') | KeyCode::Esc => Command::Quit,
+        KeyCode::Char('c') if modifiers.contains(KeyModifiers::CONTROL) => Command::Quit,
+        KeyCode::Char(' ') => Command::Pause,
+        KeyCode::Char('n') | KeyCode::Enter => Command::Step,
+        KeyCode::Char('+') | KeyCode::Char('=') => Command::Speed(2.0),
+        KeyCode::Char('-') => Command::Speed(0.5),
+        KeyCode::Left => Command::Scroll(-page, 0),
+        KeyCode::Right => Command::Scroll(page, 0),
+        KeyCode::Up => Command::Scroll(0, -page),
+        KeyCode::Down => Command::Scroll(0, page),
+        _ => Command::Scroll(0, 0),
+    })
+}
+
+/// Draw the part of `frame` seen through `viewport` with a status line
+fn draw(frame: &Frame, viewport: Bounds, status: &str) -> Result<()> {
+    let mut stdout = io::stdout();
+    queue!(
+        stdout,
+        cursor::MoveTo(0, 0),
+        terminal::Clear(ClearType::All)
+    )?;
+
+    let view = frame.crop(viewport);
+    for line in view.to_ansi().lines() {
+        write!(stdout, "{}\r\n", line)?;
+    }
+    write!(stdout, "{}\r\n{}", status, HELP)?;
+    stdout.flush()?;
+    Ok(())
+}
+
+/// Viewport of the frames, moved around by the viewer
+fn viewport(options: &Options, scroll: Point) -> Result<Bounds> {
+    let bounds = match options.viewport {
+        Some(viewport) => viewport,
+        None => {
+            // Keep two lines for the status
+            let (columns, rows) = terminal::size()?;
+            Bounds {
+                min: Point::ORIGIN,
+                max: Point::new(columns as isize - 1, rows as isize - 3),
+            }
+        }
+    };
+    Ok(Bounds {
+        min: bounds.min + scroll,
+        max: bounds.max + scroll,
+    })
+}
+
+/// Play `simulation` step by step, with the keyboard controls when in a terminal
+pub fn play(simulation: &mut dyn Simulation, title: &str, options: &Options) -> Result<()> {
+    if !io::stdout().is_terminal() || !io::stdin().is_terminal() {
+        return play_frames(simulation, title, options);
+    }
+
+    let _screen = Screen::enter()?;
+    let mut fps = options.fps.clamp(MIN_FPS, MAX_FPS);
+    let mut paused = options.paused;
+    let mut scroll = Point::ORIGIN;
+    let mut steps = 0;
+    let mut over = false;
+
+    loop {
+        let frame = simulation.render();
+        let state = if over {
+            "over"
+        } else if paused {
+            "paused"
+        } else {
+            "running"
+        };
+        let status = format!(
+            "{} | step {} | {} | {} fps | {}",
+            title, steps, state, fps, frame.caption
+        );
+        draw(&frame, viewport(options, scroll)?, &status)?;
+
+        let timeout = (!paused && !over).then(|| Duration::from_secs_f64(1.0 / fps));
+        let advance = match next_command(timeout)? {
+            Command::Next => true,
+            Command::Step => {
+                paused = true;
+                true
+            }
+            Command::Pause => {
+                paused = !paused;
+                false
+            }
+            Command::Speed(factor) => {
+                fps = (fps * factor).clamp(MIN_FPS, MAX_FPS);
+                false
+            }
+            Command::Scroll(dx, dy) => {
+                scroll += Point::new(dx, dy);
+                false
+            }
+            Command::Quit => return Ok(()),
+        };
+
+        if advance && !over {
+            over = !simulation.step();
+            if !over {
+                steps += 1;
+            }
+        }
+    }
+}
+
+/// Print every frame one after the other, when the output is not a terminal
+fn play_frames(simulation: &mut dyn Simulation, title: &str, options: &Options) -> Result<()> {
+    let delay = Duration::from_secs_f64(1.0 / options.fps.clamp(MIN_FPS, MAX_FPS));
+    let mut stdout = io::stdout().lock();
+
+    for step in 0.. {
+        let frame = simulation.render();
+        let frame = match options.viewport {
+            Some(viewport) => frame.crop(viewport),
+            None => frame,
+        };
+        writeln!(stdout, "{} | step {} | {}", title, step, frame.caption)?;
+        writeln!(stdout, "{}", frame)?;
+        stdout.flush()?;
+
+        if !simulation.step() {
+            break;
+        }
+        thread::sleep(delay);
+    }
+
+    Ok(())
+}
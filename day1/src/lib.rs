@@ -0,0 +1,385 @@
+// https://adventofcode.com/2022/day/1
+
+use std::{
+    cmp::Reverse,
+    collections::BinaryHeap,
+    fmt::{self, Display},
+    io::{self, BufRead},
+    num::ParseIntError,
+};
+
+use aoc_common::{Example, ParseError, Result, Solution};
+
+/// Items carried by one elf
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Elf {
+    /// Position of the elf in the inventory, from 0
+    pub index: usize,
+    pub calories: i64,
+    pub items: usize,
+}
+
+/// Line of the inventory that is neither a number of calories nor a separator
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MalformedLine {
+    /// Line number, from 1
+    pub line: usize,
+    pub text: String,
+    pub error: ParseIntError,
+}
+
+impl Display for MalformedLine {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {} in `{}`", self.line, self.error, self.text)
+    }
+}
+
+impl std::error::Error for MalformedLine {}
+
+/// Groups the lines of an inventory into elves, one line at a time
+///
+/// Elves are separated by blank lines, several blank lines in a row count as
+/// one separator.
+#[derive(Debug, Default, Clone)]
+pub struct Inventory {
+    elves: usize,
+    lines: usize,
+    calories: i64,
+    items: usize,
+}
+
+impl Inventory {
+    pub fn new() -> Self {
+        Inventory::default()
+    }
+
+    /// Add a line, returns the elf it completes if it is a separator
+    ///
+    /// A malformed line is left out of the current elf.
+    pub fn push_line(&mut self, line: &str) -> Result<Option<Elf>, MalformedLine> {
+        self.lines += 1;
+        let line = line.trim_end_matches(['\n', '\r']);
+        if line.trim().is_empty() {
+            return Ok(self.end_elf());
+        }
+
+        let calories = line.trim().parse::<i64>().map_err(|error| MalformedLine {
+            line: self.lines,
+            text: line.to_string(),
+            error,
+        })?;
+        self.calories += calories;
+        self.items += 1;
+        Ok(None)
+    }
+
+    /// The last elf, if the inventory does not end with a separator
+    pub fn finish(mut self) -> Option<Elf> {
+        self.end_elf()
+    }
+
+    fn end_elf(&mut self) -> Option<Elf> {
+        if self.items == 0 {
+            return None;
+        }
+
+        let elf = Elf {
+            index: self.elves,
+            calories: self.calories,
+            items: self.items,
+        };
+        self.elves += 1;
+        self.calories = 0;
+        self.items = 0;
+        Some(elf)
+    }
+}
+
+/// Read an inventory line by line, `malformed` is called for the lines that are skipped
+pub fn read_elves(
+    mut reader: impl BufRead,
+    mut malformed: impl FnMut(MalformedLine),
+    mut elf: impl FnMut(Elf),
+) -> io::Result<()> {
+    let mut inventory = Inventory::new();
+    let mut line = String::new();
+
+    while reader.read_line(&mut line)? > 0 {
+        match inventory.push_line(&line) {
+            Ok(Some(done)) => elf(done),
+            Ok(None) => (),
+            Err(error) => malformed(error),
+        }
+        line.clear();
+    }
+    inventory.finish().into_iter().for_each(elf);
+
+    Ok(())
+}
+
+/// The `k` elves carrying the most calories, keeping only them in memory
+///
+/// ```
+/// use day1::TopK;
+///
+/// let mut top = TopK::new(2);
+/// for (elf, calories) in [100, 300, 200, 300].into_iter().enumerate() {
+///     top.push(elf, calories);
+/// }
+/// assert_eq!(top.into_ranked(), [(1, 300), (3, 300)]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct TopK {
+    k: usize,
+    /// Smallest total on top, the latest elf first on ties
+    heap: BinaryHeap<Reverse<(i64, Reverse<usize>)>>,
+}
+
+impl TopK {
+    pub fn new(k: usize) -> Self {
+        TopK {
+            k,
+            heap: BinaryHeap::with_capacity(k + 1),
+        }
+    }
+
+    pub fn push(&mut self, index: usize, calories: i64) {
+        self.heap.push(Reverse((calories, Reverse(index))));
+        if self.heap.len() > self.k {
+            self.heap.pop();
+        }
+    }
+
+    /// `(elf index, calories)` from the most calories, the first elf first on ties
+    pub fn into_ranked(self) -> Vec<(usize, i64)> {
+        self.heap
+            .into_sorted_vec()
+            .into_iter()
+            .map(|Reverse((calories, Reverse(index)))| (index, calories))
+            .collect()
+    }
+}
+
+/// Ranked `k` elves carrying the most calories in a whole inventory
+pub fn top_k(
+    reader: impl BufRead,
+    k: usize,
+    malformed: impl FnMut(MalformedLine),
+) -> io::Result<Vec<(usize, i64)>> {
+    let mut top = TopK::new(k);
+    read_elves(reader, malformed, |elf| top.push(elf.index, elf.calories))?;
+    Ok(top.into_ranked())
+}
+
+/// Values sorted for the statistics
+///
+/// ```
+/// use day1::Distribution;
+///
+/// let calories = Distribution::new([6000, 4000, 11000, 24000, 10000]);
+/// assert_eq!(calories.mean(), Some(11000.0));
+/// assert_eq!(calories.median(), Some(10000.0));
+/// assert_eq!(calories.percentile(90.0), Some(24000));
+/// assert_eq!((calories.min(), calories.max()), (Some(4000), Some(24000)));
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Distribution {
+    values: Vec<i64>,
+}
+
+impl Distribution {
+    pub fn new(values: impl IntoIterator<Item = i64>) -> Self {
+        let mut values: Vec<i64> = values.into_iter().collect();
+        values.sort_unstable();
+        Distribution { values }
+    }
+
+    pub fn count(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn min(&self) -> Option<i64> {
+        self.values.first().copied()
+    }
+
+    pub fn max(&self) -> Option<i64> {
+        self.values.last().copied()
+    }
+
+    pub fn mean(&self) -> Option<f64> {
+        let sum: i64 = self.values.iter().sum();
+        (!self.values.is_empty()).then(|| sum as f64 / self.values.len() as f64)
+    }
+
+    /// Middle value, the mean of the two middle ones for an even count
+    pub fn median(&self) -> Option<f64> {
+        let n = self.values.len();
+        match n {
+            0 => None,
+            _ if n % 2 == 1 => Some(self.values[n / 2] as f64),
+            _ => Some((self.values[n / 2 - 1] + self.values[n / 2]) as f64 / 2.0),
+        }
+    }
+
+    /// Smallest value with at least `percent` % of the values below or equal to it
+    pub fn percentile(&self, percent: f64) -> Option<i64> {
+        let rank = (percent / 100.0 * self.values.len() as f64).ceil() as usize;
+        self.values
+            .get(rank.clamp(1, self.values.len().max(1)) - 1)
+            .copied()
+    }
+
+    /// `bins` lines of bars counting the values between the bounds of each line,
+    /// the longest bar is `width` characters
+    pub fn histogram(&self, bins: usize, width: usize) -> String {
+        let (Some(min), Some(max)) = (self.min(), self.max()) else {
+            return String::new();
+        };
+        let bins = (bins.max(1) as i64).min(max - min + 1);
+        let size = (max - min) / bins + 1;
+
+        let mut counts = vec![0; bins as usize];
+        for value in &self.values {
+            counts[((value - min) / size) as usize] += 1;
+        }
+        let most = counts.iter().copied().max().unwrap_or(1);
+        let top = min + bins * size - 1;
+        let digits = top.to_string().len().max(min.to_string().len());
+
+        let mut text = String::new();
+        for (bin, count) in counts.into_iter().enumerate() {
+            let low = min + bin as i64 * size;
+            let bar = "#".repeat(count * width / most);
+            text += &format!(
+                "{:>digits$} - {:>digits$} | {} {}\n",
+                low,
+                low + size - 1,
+                bar,
+                count
+            );
+        }
+        text
+    }
+}
+
+/// Distribution of the elves of an inventory
+#[derive(Debug, Default, Clone)]
+pub struct Statistics {
+    /// Calories carried by each elf
+    pub calories: Distribution,
+    /// Items carried by each elf
+    pub items: Distribution,
+    /// Lines that are neither calories nor separators
+    pub malformed: usize,
+}
+
+impl Statistics {
+    pub fn from_elves(elves: impl IntoIterator<Item = Elf>) -> Self {
+        let (calories, items): (Vec<_>, Vec<_>) = elves
+            .into_iter()
+            .map(|elf| (elf.calories, elf.items as i64))
+            .unzip();
+        Statistics {
+            calories: Distribution::new(calories),
+            items: Distribution::new(items),
+            malformed: 0,
+        }
+    }
+
+    /// Statistics of a whole inventory, the malformed lines are counted and skipped
+    pub fn read(reader: impl BufRead) -> io::Result<Self> {
+        let mut elves = Vec::new();
+        let mut malformed = 0;
+        read_elves(reader, |_| malformed += 1, |elf| elves.push(elf))?;
+        Ok(Statistics {
+            malformed,
+            ..Statistics::from_elves(elves)
+        })
+    }
+}
+
+/// Report with a table of the statistics and the histogram of the calories
+impl Display for Statistics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{} elves, {} malformed lines skipped",
+            self.calories.count(),
+            self.malformed
+        )?;
+        writeln!(
+            f,
+            "{:<9}{:>12}{:>12}{:>10}{:>10}{:>10}{:>10}",
+            "", "mean", "median", "p90", "p99", "min", "max"
+        )?;
+        for (name, distribution) in [("calories", &self.calories), ("items", &self.items)] {
+            let show = |value: Option<i64>| value.map_or("-".to_string(), |v| v.to_string());
+            let show_f =
+                |value: Option<f64>| value.map_or("-".to_string(), |v| format!("{:.1}", v));
+            writeln!(
+                f,
+                "{:<9}{:>12}{:>12}{:>10}{:>10}{:>10}{:>10}",
+                name,
+                show_f(distribution.mean()),
+                show_f(distribution.median()),
+                show(distribution.percentile(90.0)),
+                show(distribution.percentile(99.0)),
+                show(distribution.min()),
+                show(distribution.max()),
+            )?;
+        }
+        writeln!(f)?;
+        writeln!(f, "calories:")?;
+        write!(f, "{}", self.calories.histogram(10, 40))
+    }
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+
+    /// `(elf index, calories)` of the 3 elves carrying the most calories
+    type Input = Vec<(usize, i64)>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: include_str!("../example"),
+        part1: Some("24000"),
+        part2: Some("45000"),
+    }];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut inventory = Inventory::new();
+        let mut top = TopK::new(3);
+
+        for line in input.lines() {
+            let elf = inventory.push_line(line).map_err(|e| {
+                ParseError::new(line, line, e.error.to_string()).within(input, line)
+            })?;
+            if let Some(elf) = elf {
+                top.push(elf.index, elf.calories);
+            }
+        }
+        if let Some(elf) = inventory.finish() {
+            top.push(elf.index, elf.calories);
+        }
+
+        if top.heap.is_empty() {
+            return Err(ParseError::missing(
+                input,
+                "expected the calories of an elf",
+            ));
+        }
+        Ok(top.into_ranked())
+    }
+
+    fn part1(top: &Self::Input) -> Result<Self::Answer1> {
+        Ok(top[0].1)
+    }
+
+    fn part2(top: &Self::Input) -> Result<Self::Answer2> {
+        Ok(top.iter().map(|(_, calories)| calories).sum())
+    }
+}
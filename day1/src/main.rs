@@ -1,43 +1,42 @@
 // https://adventofcode.com/2022/day/1
 
-use std::{collections::BinaryHeap, fs::File, io::Read};
-
-fn main() {
-    println!("Hello, world!");
-    let mut input = File::open("input").unwrap();
-    let input = {
-        let mut str = String::new();
-        input.read_to_string(&mut str).unwrap();
-        str
-    };
-
-    let mut sums = BinaryHeap::new();
-    let mut sum = 0;
-
-    for line in input.lines() {
-        if let Ok(calori) = line.parse::<i64>() {
-            sum += calori;
-        } else {
-            sums.push(sum);
-            sum = 0;
+use std::{env, fs::File, io::BufReader};
+
+use anyhow::Context;
+use day1::{top_k, Statistics};
+
+/// Without arguments, solve the puzzle input, else report the statistics of
+/// the inventories given
+fn main() -> anyhow::Result<()> {
+    let inventories: Vec<String> = env::args().skip(1).collect();
+
+    for (i, path) in inventories.iter().enumerate() {
+        let file = File::open(path).with_context(|| format!("Cannot open {}", path))?;
+        let statistics = Statistics::read(BufReader::new(file))?;
+
+        if i > 0 {
+            println!();
         }
+        print!("{}: {}", path, statistics);
+    }
+    if !inventories.is_empty() {
+        return Ok(());
     }
-    sums.push(sum);
 
-    println!(
-        "The elves with the most calories has: {}",
-        sums.peek().unwrap()
-    );
+    let input = BufReader::new(File::open("input")?);
+    let top = top_k(input, 3, |line| eprintln!("Skipping {}", line))?;
 
-    let top_3 = sums
-        .into_sorted_vec()
-        .iter()
-        .rev()
-        .take(3)
-        .fold(0, |sum, cal| sum + cal);
+    if let Some((elf, calories)) = top.first() {
+        println!(
+            "The elves with the most calories has: {} (elf {})",
+            calories, elf
+        );
+    }
 
     println!(
         "The 3 elves with the most calories have a total of: {}",
-        top_3
+        top.iter().map(|(_, calories)| calories).sum::<i64>()
     );
+
+    Ok(())
 }
@@ -0,0 +1,196 @@
+// https://adventofcode.com/2022/day/10
+
+use std::str::FromStr;
+
+use aoc_common::{
+    next_token, parse_lines, parse_token, Example, Frame, ParseError, Part, Render, Result,
+    Simulation, Solution,
+};
+
+#[derive(Clone)]
+pub enum Instruction {
+    Add(i32),
+    Noop,
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut elements = s.split(' ');
+        match next_token(s, &mut elements, "an instruction")? {
+            "addx" => Ok(Instruction::Add(parse_token(
+                s,
+                next_token(s, &mut elements, "a value")?,
+            )?)),
+            "noop" => Ok(Instruction::Noop),
+            instruction => Err(ParseError::new(s, instruction, "expected `addx` or `noop`")),
+        }
+    }
+}
+
+pub struct Processor {
+    program: Vec<Instruction>,
+    cycle: usize,
+    pc: usize,
+    pub x: i32,
+    temp_x: i32,
+    exec_add: bool,
+}
+
+impl Processor {
+    pub fn new(program: Vec<Instruction>) -> Self {
+        Processor {
+            program,
+            cycle: 0,
+            pc: 0,
+            x: 1,
+            temp_x: 1,
+            exec_add: false,
+        }
+    }
+
+    pub fn step(&mut self) -> Option<usize> {
+        self.cycle += 1;
+        self.x = self.temp_x;
+
+        match (self.exec_add, self.program.get(self.pc)) {
+            (false, Some(Instruction::Add(_))) => {
+                self.exec_add = true;
+                Some(self.cycle)
+            }
+            (true, Some(Instruction::Add(n))) => {
+                self.temp_x += n;
+                self.pc += 1;
+                self.exec_add = false;
+                Some(self.cycle)
+            }
+            (_, Some(Instruction::Noop)) => {
+                self.pc += 1;
+                Some(self.cycle)
+            }
+            (_, None) => None,
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct Beam {
+    position: i32,
+}
+
+impl Beam {
+    pub fn step(&mut self) -> bool {
+        self.position = (self.position + 1) % 40;
+        self.position == 0
+    }
+
+    pub fn is_lit(&self, x: i32) -> bool {
+        (x - self.position).abs() < 2
+    }
+}
+
+/// CRT drawing the picture while the program runs, one cycle at a time
+pub struct Crt {
+    processor: Processor,
+    beam: Beam,
+    cycle: usize,
+    pub screen: String,
+}
+
+impl Crt {
+    pub fn new(program: Vec<Instruction>) -> Self {
+        Crt {
+            processor: Processor::new(program),
+            beam: Beam::default(),
+            cycle: 0,
+            screen: String::new(),
+        }
+    }
+}
+
+impl Simulation for Crt {
+    fn step(&mut self) -> bool {
+        match self.processor.step() {
+            Some(cycle) => {
+                self.cycle = cycle;
+                if self.beam.is_lit(self.processor.x) {
+                    self.screen.push('🎅');
+                } else {
+                    self.screen.push('🎄');
+                }
+
+                if self.beam.step() {
+                    self.screen.push('\n');
+                }
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+impl Render for Crt {
+    fn render(&self) -> Frame {
+        Frame::from_text(&self.screen)
+            .with_caption(format!("cycle {}, X = {}", self.cycle, self.processor.x))
+    }
+}
+
+/// Picture drawn by the example program
+const EXAMPLE_SCREEN: &str = concat!(
+    "🎅🎅🎄🎄🎅🎅🎄🎄🎅🎅🎄🎄🎅🎅🎄🎄🎅🎅🎄🎄🎅🎅🎄🎄🎅🎅🎄🎄🎅🎅🎄🎄🎅🎅🎄🎄🎅🎅🎄🎄\n",
+    "🎅🎅🎅🎄🎄🎄🎅🎅🎅🎄🎄🎄🎅🎅🎅🎄🎄🎄🎅🎅🎅🎄🎄🎄🎅🎅🎅🎄🎄🎄🎅🎅🎅🎄🎄🎄🎅🎅🎅🎄\n",
+    "🎅🎅🎅🎅🎄🎄🎄🎄🎅🎅🎅🎅🎄🎄🎄🎄🎅🎅🎅🎅🎄🎄🎄🎄🎅🎅🎅🎅🎄🎄🎄🎄🎅🎅🎅🎅🎄🎄🎄🎄\n",
+    "🎅🎅🎅🎅🎅🎄🎄🎄🎄🎄🎅🎅🎅🎅🎅🎄🎄🎄🎄🎄🎅🎅🎅🎅🎅🎄🎄🎄🎄🎄🎅🎅🎅🎅🎅🎄🎄🎄🎄🎄\n",
+    "🎅🎅🎅🎅🎅🎅🎄🎄🎄🎄🎄🎄🎅🎅🎅🎅🎅🎅🎄🎄🎄🎄🎄🎄🎅🎅🎅🎅🎅🎅🎄🎄🎄🎄🎄🎄🎅🎅🎅🎅\n",
+    "🎅🎅🎅🎅🎅🎅🎅🎄🎄🎄🎄🎄🎄🎄🎅🎅🎅🎅🎅🎅🎅🎄🎄🎄🎄🎄🎄🎄🎅🎅🎅🎅🎅🎅🎅🎄🎄🎄🎄🎄\n",
+);
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = Vec<Instruction>;
+    type Answer1 = i32;
+    /// Picture drawn on the CRT
+    type Answer2 = String;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: include_str!("../example"),
+        part1: Some("13140"),
+        part2: Some(EXAMPLE_SCREEN),
+    }];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input, Instruction::from_str)
+    }
+
+    fn part1(program: &Self::Input) -> Result<Self::Answer1> {
+        let mut processor = Processor::new(program.clone());
+
+        let trace_cycles = [20, 60, 100, 140, 180, 220];
+
+        let mut result = 0;
+
+        while let Some(cycle) = processor.step() {
+            if trace_cycles.contains(&cycle) {
+                result += cycle as i32 * processor.x;
+            }
+        }
+
+        Ok(result)
+    }
+
+    fn part2(program: &Self::Input) -> Result<Self::Answer2> {
+        let mut crt = Crt::new(program.clone());
+        while crt.step() {}
+
+        Ok(crt.screen)
+    }
+
+    fn simulation(program: &Self::Input, _part: Part) -> Option<Box<dyn Simulation>> {
+        Some(Box::new(Crt::new(program.clone())))
+    }
+}
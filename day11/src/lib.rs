@@ -0,0 +1,319 @@
+// https://adventofcode.com/2022/day/11
+
+use std::{
+    ops::{Add, DivAssign, Mul, Rem, RemAssign},
+    str::{FromStr, Lines},
+};
+
+use aoc_common::{next_token, parse_token, Example, ParseError, Result, Solution};
+
+#[derive(Debug, Clone)]
+pub enum Operand<T> {
+    Old,
+    Some(T),
+}
+
+impl<T> FromStr for Operand<T>
+where
+    T: FromStr,
+    <T as FromStr>::Err: std::fmt::Debug + Sync + Send + std::error::Error,
+{
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "old" => Ok(Operand::Old),
+            _ => Ok(Operand::Some(parse_token(s, s)?)),
+        }
+    }
+}
+
+impl<T> Operand<T>
+where
+    T: Clone,
+{
+    fn get(&self, old: T) -> T {
+        match self {
+            Operand::Old => old,
+            Operand::Some(value) => value.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Operation<T> {
+    a: Operand<T>,
+    b: Operand<T>,
+    plus: bool,
+}
+
+impl<T> FromStr for Operation<T>
+where
+    T: FromStr,
+    <T as FromStr>::Err: std::fmt::Debug + Sync + Send + std::error::Error,
+{
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let rest = s
+            .strip_prefix("new = ")
+            .ok_or_else(|| ParseError::new(s, s, "expected `new = `"))?;
+        let mut elements = rest.split(' ');
+        let operand = |elements: &mut std::str::Split<'_, char>| {
+            let operand = next_token(s, elements, "an operand")?;
+            Operand::from_str(operand).map_err(|e| e.within(s, operand))
+        };
+
+        let a = operand(&mut elements)?;
+        let plus = match next_token(s, &mut elements, "an operator")? {
+            "+" => true,
+            "*" => false,
+            operator => return Err(ParseError::new(s, operator, "expected `+` or `*`")),
+        };
+        let b = operand(&mut elements)?;
+
+        Ok(Operation { a, b, plus })
+    }
+}
+
+impl<T> Operation<T>
+where
+    T: Clone + Mul<Output = T> + Add<Output = T>,
+{
+    fn execute(&self, old: T) -> T {
+        let a = self.a.get(old.clone());
+        let b = self.b.get(old);
+        match self.plus {
+            false => a * b,
+            true => a + b,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Monkey<T> {
+    pub items: Vec<T>,
+    operation: Operation<T>,
+    pub test_div_by: T,
+    send_if_true: usize,
+    send_if_false: usize,
+}
+
+/// Value of the next line of `block`, which must look like `label: value`
+fn field<'a>(block: &'a str, lines: &mut Lines<'a>, label: &str) -> Result<&'a str, ParseError> {
+    let line = next_token(block, lines, &format!("`{}:`", label))?;
+    line.trim_start()
+        .strip_prefix(label)
+        .and_then(|value| value.strip_prefix(':'))
+        .map(|value| value.trim_start_matches(' '))
+        .ok_or_else(|| ParseError::new(block, line, format!("expected `{}:`", label)))
+}
+
+/// Number at the end of `value`, which must start with `prefix`
+fn number_after<T>(block: &str, value: &str, prefix: &str) -> Result<T, ParseError>
+where
+    T: FromStr,
+    T::Err: std::fmt::Display,
+{
+    let number = value
+        .strip_prefix(prefix)
+        .ok_or_else(|| ParseError::new(block, value, format!("expected `{}`", prefix)))?;
+    parse_token(block, number)
+}
+
+impl<T> FromStr for Monkey<T>
+where
+    T: FromStr + From<u32> + PartialEq,
+    <T as FromStr>::Err: std::fmt::Debug + Sync + Send + std::error::Error,
+{
+    type Err = ParseError;
+
+    /// Parse the block of lines describing one monkey
+    fn from_str(block: &str) -> Result<Self, Self::Err> {
+        let mut lines = block.lines();
+
+        let header = next_token(block, &mut lines, "`Monkey N:`")?;
+        if !header.starts_with("Monkey ") {
+            return Err(ParseError::new(block, header, "expected `Monkey N:`"));
+        }
+
+        let items = field(block, &mut lines, "Starting items")?;
+        let items = if items.is_empty() {
+            Vec::new()
+        } else {
+            items
+                .split(", ")
+                .map(|item| parse_token(block, item))
+                .collect::<Result<_, _>>()?
+        };
+
+        let operation = field(block, &mut lines, "Operation")?;
+        let operation = operation
+            .parse()
+            .map_err(|e: ParseError| e.within(block, operation))?;
+
+        let test = field(block, &mut lines, "Test")?;
+        let test_div_by: T = number_after(block, test, "divisible by ")?;
+        if test_div_by == 0.into() {
+            let divisor = test.strip_prefix("divisible by ").unwrap_or(test);
+            return Err(ParseError::new(
+                block,
+                divisor,
+                "expected a divisor above 0",
+            ));
+        }
+        let if_true = field(block, &mut lines, "If true")?;
+        let send_if_true = number_after(block, if_true, "throw to monkey ")?;
+        let if_false = field(block, &mut lines, "If false")?;
+        let send_if_false = number_after(block, if_false, "throw to monkey ")?;
+
+        Ok(Monkey {
+            items,
+            operation,
+            test_div_by,
+            send_if_true,
+            send_if_false,
+        })
+    }
+}
+
+impl<T> Monkey<T>
+where
+    T: Clone
+        + FromStr
+        + std::fmt::Debug
+        + Mul<Output = T>
+        + Add<Output = T>
+        + DivAssign
+        + From<u32>
+        + Rem,
+    <T as FromStr>::Err: std::fmt::Debug + Sync + Send + std::error::Error,
+    <T as Rem>::Output: PartialEq<T>,
+{
+    pub fn execute(&mut self, relief: bool) -> Vec<(usize, T)> {
+        let mut transfers = Vec::new();
+        for item in self.items.iter() {
+            let mut worry = self.operation.execute(item.clone());
+            if relief {
+                worry /= 3.into();
+            }
+            if worry.clone() % self.test_div_by.clone() == 0.into() {
+                transfers.push((self.send_if_true, worry));
+            } else {
+                transfers.push((self.send_if_false, worry));
+            }
+        }
+
+        self.items = Vec::new();
+
+        transfers
+    }
+}
+
+// This can (but does not have to) be `num::BigUint`, `aoc bench --day 11` tells
+// which one is faster: last time u64 won, the bigint implementations were ~50% slower
+pub type NumImplementation = u64;
+
+/// Let the monkeys play and return the product of the two highest inspection counts
+///
+/// Without relief the worry levels are kept in check by the common divider of all
+/// the monkeys' tests, which does not change where the items are thrown.
+pub fn monkey_business<T>(monkeys: &[Monkey<T>], rounds: usize, relief: bool) -> usize
+where
+    T: Clone
+        + FromStr
+        + std::fmt::Debug
+        + std::fmt::Display
+        + Mul<Output = T>
+        + Add<Output = T>
+        + DivAssign
+        + RemAssign
+        + From<u32>
+        + Rem,
+    <T as FromStr>::Err: std::fmt::Debug + Sync + Send + std::error::Error,
+    <T as Rem>::Output: PartialEq<T>,
+{
+    let mut monkeys = monkeys.to_vec();
+    let mut n_inspections: Vec<_> = monkeys.iter().map(|_| 0).collect();
+
+    let common_divider: T = monkeys
+        .iter()
+        .map(|m| m.test_div_by.clone())
+        .fold(1u32.into(), |acc, val| acc * val);
+
+    for _ in 0..rounds {
+        for i in 0..monkeys.len() {
+            n_inspections[i] += monkeys[i].items.len();
+            let transfers = monkeys[i].execute(relief);
+
+            for (index, item) in transfers.iter() {
+                monkeys[*index].items.push(item.clone());
+            }
+        }
+
+        // Keep worry in check!
+        if !relief {
+            for monkey in monkeys.iter_mut() {
+                for item in monkey.items.iter_mut() {
+                    *item %= common_divider.clone()
+                }
+            }
+        }
+    }
+
+    n_inspections.sort();
+    n_inspections.reverse();
+
+    n_inspections[0] * n_inspections[1]
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input = Vec<Monkey<NumImplementation>>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: include_str!("../example"),
+        part1: Some("10605"),
+        part2: Some("2713310158"),
+    }];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let monkeys: Self::Input = input
+            .split("\n\n")
+            .map(|block| {
+                block
+                    .parse()
+                    .map_err(|e: ParseError| e.within(input, block))
+            })
+            .collect::<Result<_, _>>()?;
+
+        // Monkeys can only throw to the ones that exist
+        for (i, monkey) in monkeys.iter().enumerate() {
+            for target in [monkey.send_if_true, monkey.send_if_false] {
+                if target >= monkeys.len() {
+                    let block = input.split("\n\n").nth(i).unwrap_or(input);
+                    return Err(ParseError::new(
+                        input,
+                        block,
+                        format!("there is no monkey {} to throw to", target),
+                    ));
+                }
+            }
+        }
+
+        Ok(monkeys)
+    }
+
+    fn part1(monkeys: &Self::Input) -> Result<Self::Answer1> {
+        Ok(monkey_business(monkeys, 20, true))
+    }
+
+    fn part2(monkeys: &Self::Input) -> Result<Self::Answer2> {
+        Ok(monkey_business(monkeys, 10_000, false))
+    }
+}
@@ -0,0 +1,230 @@
+// https://adventofcode.com/2022/day/12
+
+use std::{
+    collections::HashSet,
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+use anyhow::anyhow;
+use aoc_common::{
+    hsl, Bfs, DenseGrid, Example, Frame, Glyph, Grid, ParseError, Part, Point, Render, Result,
+    Simulation, Solution,
+};
+
+#[derive(Debug, Clone, Copy)]
+pub enum Terrain {
+    Free(u8),
+    Visited(u8),
+    Start,
+    End,
+}
+
+impl TryFrom<char> for Terrain {
+    type Error = char;
+
+    fn try_from(c: char) -> Result<Terrain, Self::Error> {
+        match c {
+            'S' => Ok(Terrain::Start),
+            'E' => Ok(Terrain::End),
+            'a'..='z' => Ok(Terrain::Free(c as u8 - b'a')),
+            _ => Err(c),
+        }
+    }
+}
+
+impl Terrain {
+    pub fn height(self) -> u8 {
+        match self {
+            Terrain::End => 25,
+            Terrain::Start => 0,
+            Terrain::Free(n) => n,
+            Terrain::Visited(n) => n,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Maze {
+    field: DenseGrid<Terrain>,
+    search: Bfs<Point>,
+    pub end: Point,
+    reverse: bool,
+}
+
+impl FromStr for Maze {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let field = DenseGrid::parse(s, "a-z, S or E", |c| Terrain::try_from(c).ok())?;
+
+        let start = field
+            .position(|terrain| matches!(terrain, Terrain::Start))
+            .ok_or_else(|| ParseError::missing(s, "expected a start S"))?;
+        let end = field
+            .position(|terrain| matches!(terrain, Terrain::End))
+            .ok_or_else(|| ParseError::missing(s, "expected an end E"))?;
+
+        Ok(Self {
+            field,
+            search: Bfs::new(start),
+            end,
+            reverse: false,
+        })
+    }
+}
+
+impl Maze {
+    pub fn get_tile(&self, p: Point) -> Option<Terrain> {
+        self.field.get(p).copied()
+    }
+
+    /// Distance from where the search started, `None` when `p` is not reached (yet)
+    pub fn get_distance(&self, p: Point) -> Option<usize> {
+        self.search.paths().cost(&p)
+    }
+
+    /// Shortest route from where the search started to `p`, both included
+    pub fn route(&self, p: Point) -> Option<Vec<Point>> {
+        self.search.paths().path(&p)
+    }
+
+    /// Start the search from the end and walk downhill
+    pub fn reverse(&mut self) {
+        self.search = Bfs::new(self.end);
+        self.reverse = true;
+    }
+
+    /// Distance of the closest 'a' (0) once the reverse search is done
+    pub fn closest_lowest_distance(&self) -> Option<usize> {
+        self.search
+            .paths()
+            .iter()
+            .filter(|(p, _)| self.field[**p].height() == 0)
+            .map(|(_, distance)| distance)
+            .min()
+    }
+
+    // Walk one step of the BFS path finding algorithm
+    // Returns true when we have reached the end!
+    pub fn step(&mut self) -> bool {
+        let field = &self.field;
+        let reverse = self.reverse;
+        let mut climbable = |p: &Point| {
+            let height = field[*p].height();
+            field.neighbours(*p).filter(move |&neighbor| {
+                let neighbor_height = field[neighbor].height();
+                if !reverse {
+                    neighbor_height < height + 2
+                } else {
+                    neighbor_height + 2 > height
+                }
+            })
+        };
+
+        match self.search.step(&mut climbable) {
+            Some(position) => {
+                // Keep the tile where the search started as it is
+                if self.get_distance(position) != Some(0) {
+                    self.field[position] = Terrain::Visited(self.field[position].height());
+                }
+                false
+            }
+            None => true,
+        }
+    }
+}
+
+impl Simulation for Maze {
+    // `Maze::step` tells when the search is over, the simulation when it goes on
+    fn step(&mut self) -> bool {
+        !Maze::step(self)
+    }
+}
+
+/// The visited squares are red, the route to the end is yellow once it is found
+impl Render for Maze {
+    fn render(&self) -> Frame {
+        let route: HashSet<Point> = match self.reverse {
+            false => self
+                .route(self.end)
+                .unwrap_or_default()
+                .into_iter()
+                .collect(),
+            true => HashSet::new(),
+        };
+
+        let glyphs = self.field.map(|tile| match tile {
+            Terrain::Visited(n) => Glyph::colored((n + b'a') as char, [255, 0, 0]),
+            Terrain::Free(n) => {
+                Glyph::colored((n + b'a') as char, hsl(0.5, 1.0, 0.2 + *n as f32 / 25.0))
+            }
+            Terrain::Start => Glyph::new('S'),
+            Terrain::End => Glyph::new('E'),
+        });
+        let mut frame = Frame::draw(self.field.bounds().unwrap(), |p| match route.contains(&p) {
+            true => Glyph::colored(glyphs[p].ch, [255, 255, 0]),
+            false => glyphs[p],
+        });
+
+        frame.caption = match self.get_distance(self.end) {
+            Some(distance) if !self.reverse => format!("the end is {} steps away", distance),
+            _ => format!("{} squares reached", self.search.paths().len()),
+        };
+        frame
+    }
+}
+
+impl Display for Maze {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.render().to_ansi())
+    }
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Input = Maze;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: include_str!("../example"),
+        part1: Some("31"),
+        part2: Some("29"),
+    }];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.parse()
+    }
+
+    fn part1(maze: &Self::Input) -> Result<Self::Answer1> {
+        let mut maze = maze.clone();
+
+        while !maze.step() {}
+
+        maze.get_distance(maze.end)
+            .ok_or_else(|| anyhow!("The end cannot be reached"))
+    }
+
+    // Running the algorithm the other way arround ...
+    fn part2(maze: &Self::Input) -> Result<Self::Answer2> {
+        let mut maze = maze.clone();
+        maze.reverse();
+
+        while !maze.step() {}
+
+        maze.closest_lowest_distance()
+            .ok_or_else(|| anyhow!("No square of elevation a can reach the end"))
+    }
+
+    fn simulation(maze: &Self::Input, part: Part) -> Option<Box<dyn Simulation>> {
+        let mut maze = maze.clone();
+        if part == Part::Two {
+            maze.reverse();
+        }
+        Some(Box::new(maze))
+    }
+}
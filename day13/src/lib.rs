@@ -0,0 +1,159 @@
+// https://adventofcode.com/2022/day/13
+
+use std::{cmp::Ordering, fmt::Debug, str::FromStr};
+
+use aoc_common::{parse_lines, parse_token, Example, ParseError, Result, Solution};
+
+#[derive(Clone, PartialEq, Eq)]
+pub enum Element {
+    Integer(u32),
+    List(Vec<Element>),
+}
+
+impl FromStr for Element {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(raw_list) = s.strip_prefix('[') {
+            let mut raw_list = raw_list
+                .strip_suffix(']')
+                .ok_or_else(|| ParseError::missing(s, "expected `]`"))?;
+            let mut list = Vec::new();
+            while !raw_list.is_empty() {
+                // Get the position of the next "level 0" comma
+                let mut level = 0;
+                let next_coma: usize = raw_list
+                    .chars()
+                    .take_while(|c| match c {
+                        '[' => {
+                            level += 1;
+                            true
+                        }
+                        ']' => {
+                            level -= 1;
+                            true
+                        }
+                        ',' if level == 0 => false,
+                        _ => true,
+                    })
+                    .count();
+
+                // Split, parse and push!
+                let (element, rest) = raw_list.split_at(next_coma);
+                raw_list = rest.strip_prefix(',').unwrap_or(rest);
+                list.push(
+                    element
+                        .parse()
+                        .map_err(|e: ParseError| e.within(s, element))?,
+                );
+            }
+
+            Ok(Element::List(list))
+        } else {
+            Ok(Element::Integer(parse_token(s, s)?))
+        }
+    }
+}
+
+impl Debug for Element {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Integer(n) => f.write_str(&format!("{}", n))?,
+            Self::List(list) => {
+                f.write_str("[")?;
+                for element in list {
+                    f.write_str(&format!("{:?},", element))?;
+                }
+                f.write_str("]")?
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl PartialOrd<Element> for Element {
+    fn partial_cmp(&self, other: &Element) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Element {
+    fn cmp(&self, other: &Element) -> std::cmp::Ordering {
+        match (self, other) {
+            (Element::Integer(me), Element::Integer(other)) => me.cmp(other),
+            (Element::List(me), Element::List(other)) => {
+                for (me, other) in me.iter().zip(other.iter()) {
+                    let comp = me.cmp(other);
+                    if !matches!(comp, Ordering::Equal) {
+                        return comp;
+                    }
+                }
+
+                me.len().cmp(&other.len())
+            }
+            (Element::Integer(_), Element::List(_)) => {
+                let me = Element::List(vec![self.clone()]);
+                me.cmp(other)
+            }
+            (Element::List(_), Element::Integer(_)) => {
+                let other = Element::List(vec![other.clone()]);
+                self.cmp(&other)
+            }
+        }
+    }
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+
+    /// All the packets, in the order they appear
+    type Input = Vec<Element>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: include_str!("../example"),
+        part1: Some("13"),
+        part2: Some("140"),
+    }];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let packets = parse_lines(input, |line| match line {
+            "" => Ok(None),
+            _ => line.parse().map(Some),
+        })?;
+
+        Ok(packets.into_iter().flatten().collect())
+    }
+
+    fn part1(elements: &Self::Input) -> Result<Self::Answer1> {
+        let sum_of_well_ordered = elements
+            .chunks_exact(2)
+            .enumerate()
+            .filter(|(_, pair)| pair[0] <= pair[1])
+            .map(|(i, _)| i + 1)
+            .sum();
+
+        Ok(sum_of_well_ordered)
+    }
+
+    fn part2(elements: &Self::Input) -> Result<Self::Answer2> {
+        let mut elements = elements.clone();
+
+        let two: Element = "[[2]]".parse()?;
+        let six: Element = "[[6]]".parse()?;
+
+        elements.push(two.clone());
+        elements.push(six.clone());
+
+        elements.sort();
+
+        let two_position = elements.iter().position(|e| *e == two).unwrap() + 1;
+        let six_position = elements.iter().position(|e| *e == six).unwrap() + 1;
+
+        Ok(two_position * six_position)
+    }
+}
@@ -0,0 +1,222 @@
+// https://adventofcode.com/2022/day/14
+
+use std::str::FromStr;
+
+use aoc_common::{
+    next_token, parse_token, Bounds, Example, Frame, Glyph, Grid, ParseError, Part, Point, Render,
+    Result, Simulation, Solution, SparseGrid,
+};
+
+/// Where the sand comes from
+const SOURCE: Point = Point::new(500, 0);
+
+const DOWN: Point = Point::new(0, 1);
+const DOWN_LEFT: Point = Point::new(-1, 1);
+const DOWN_RIGHT: Point = Point::new(1, 1);
+
+#[derive(Debug, Default, Clone)]
+pub enum Cell {
+    #[default]
+    Air,
+    Rock,
+    Sand,
+}
+
+#[derive(Debug, Clone)]
+pub struct Cave {
+    cave: SparseGrid<Cell>,
+    bottom: isize,
+}
+
+impl FromStr for Cave {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut cave = SparseGrid::new();
+        let mut bottom = 0;
+
+        for line in s.lines() {
+            let corner = |corner: &str| parse_token::<Point>(s, corner);
+
+            let mut corners = line.split(" -> ");
+            let mut prev = corner(next_token(line, &mut corners, "a corner")?)?;
+
+            for raw_next in corners {
+                let next = corner(raw_next)?;
+                if prev.x != next.x && prev.y != next.y {
+                    return Err(ParseError::new(
+                        s,
+                        raw_next,
+                        "expected a horizontal or vertical line",
+                    ));
+                }
+
+                if prev.x == next.x {
+                    let x = prev.x;
+                    let start_y = next.y.min(prev.y);
+                    let stop_y = next.y.max(prev.y);
+                    for y in start_y..=stop_y {
+                        cave.insert(Point::new(x, y), Cell::Rock);
+                    }
+                }
+                if prev.y == next.y {
+                    let y = prev.y;
+                    let start_x = next.x.min(prev.x);
+                    let stop_x = next.x.max(prev.x);
+                    for x in start_x..=stop_x {
+                        cave.insert(Point::new(x, y), Cell::Rock);
+                    }
+                }
+
+                bottom = bottom.max(next.y);
+
+                prev = next;
+            }
+        }
+
+        Ok(Cave { cave, bottom })
+    }
+}
+
+impl Cave {
+    fn is_air(&self, p: Point) -> bool {
+        matches!(self.cave.get(p), None | Some(Cell::Air))
+    }
+
+    /// Drop one unit of sand, returns true once the simulation is over
+    ///
+    /// With a floor the sand piles up until it blocks the source, without it
+    /// the simulation stops when the first unit falls into the abyss.
+    pub fn sand_step(&mut self, floor: bool) -> bool {
+        let mut sand = SOURCE;
+
+        loop {
+            if self.is_air(sand + DOWN) {
+                sand += DOWN;
+            } else if self.is_air(sand + DOWN_LEFT) {
+                sand += DOWN_LEFT;
+            } else if self.is_air(sand + DOWN_RIGHT) {
+                sand += DOWN_RIGHT;
+            } else {
+                self.cave.insert(sand, Cell::Sand);
+                break;
+            }
+
+            // dbg!(sand);
+
+            if sand.y > self.bottom {
+                if !floor {
+                    return true;
+                }
+                self.cave.insert(sand, Cell::Sand);
+                break;
+            }
+        }
+
+        sand == SOURCE
+    }
+}
+
+/// Units of sand poured into the cave, one per step
+pub struct SandFall {
+    pub cave: Cave,
+    floor: bool,
+    /// Units of sand at rest
+    pub units: usize,
+    over: bool,
+}
+
+impl SandFall {
+    pub fn new(cave: Cave, floor: bool) -> Self {
+        SandFall {
+            cave,
+            floor,
+            units: 0,
+            over: false,
+        }
+    }
+}
+
+impl Simulation for SandFall {
+    fn step(&mut self) -> bool {
+        if self.over {
+            return false;
+        }
+        self.over = self.cave.sand_step(self.floor);
+        // Without a floor the last unit falls into the abyss
+        if !self.over || self.floor {
+            self.units += 1;
+        }
+        true
+    }
+}
+
+impl Render for SandFall {
+    fn render(&self) -> Frame {
+        let floor = self.cave.bottom + 2;
+        let mut bounds = self.cave.cave.bounds().unwrap_or(Bounds::new(SOURCE));
+        bounds.extend(SOURCE);
+        if self.floor {
+            bounds.extend(Point::new(bounds.min.x, floor));
+        }
+
+        Frame::draw(bounds, |p| match self.cave.cave.get(p) {
+            _ if p == SOURCE => '+'.into(),
+            Some(Cell::Rock) => Glyph::colored('#', [150, 150, 150]),
+            Some(Cell::Sand) => Glyph::colored('o', [230, 190, 90]),
+            _ if self.floor && p.y == floor => Glyph::colored('#', [150, 150, 150]),
+            None | Some(Cell::Air) => '.'.into(),
+        })
+        .with_caption(format!("{} units of sand at rest", self.units))
+    }
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+
+    type Input = Cave;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: include_str!("../example"),
+        part1: Some("24"),
+        part2: Some("93"),
+    }];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.parse()
+    }
+
+    fn part1(cave: &Self::Input) -> Result<Self::Answer1> {
+        let mut cave = cave.clone();
+
+        let mut n_sand = 0;
+
+        while !cave.sand_step(false) {
+            n_sand += 1;
+        }
+
+        Ok(n_sand)
+    }
+
+    fn part2(cave: &Self::Input) -> Result<Self::Answer2> {
+        let mut cave = cave.clone();
+
+        let mut n_sand = 0;
+
+        // dbg!(&cave);
+
+        while !cave.sand_step(true) {
+            n_sand += 1;
+        }
+
+        Ok(n_sand + 1)
+    }
+
+    fn simulation(cave: &Self::Input, part: Part) -> Option<Box<dyn Simulation>> {
+        Some(Box::new(SandFall::new(cave.clone(), part == Part::Two)))
+    }
+}
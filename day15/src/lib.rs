@@ -0,0 +1,209 @@
+// https://adventofcode.com/2022/day/15
+
+use std::str::FromStr;
+
+use anyhow::Error;
+use aoc_common::{next_token, parse_token, Example, ParseError, Point, Result, Solution};
+
+pub struct Sensor {
+    position: Point,
+    sensing_distance: isize,
+    beacon: Point,
+}
+
+impl Sensor {
+    pub fn can_sense(&self, target: Point) -> bool {
+        self.position.manhattan(target) <= self.sensing_distance
+    }
+}
+
+/// Sensors of the cave, with the row scanned in part 1 and the limit of the
+/// search in part 2
+///
+/// The puzzle values are used unless the input starts with a line like
+/// `row=10, limit=20`, as the example does.
+pub struct Field {
+    sensors: Vec<Sensor>,
+    pub min_sensing: Point,
+    pub max_sensing: Point,
+    pub scanned_row: isize,
+    pub search_limit: isize,
+}
+
+/// Parse `<prefix>x=X, y=Y` found in `text`, a slice of the line `line`
+fn parse_position(line: &str, text: &str, prefix: &str) -> Result<Point, ParseError> {
+    let coordinates = text
+        .strip_prefix(prefix)
+        .ok_or_else(|| ParseError::new(line, text, format!("expected `{}`", prefix)))?;
+
+    let mut coordinates = coordinates.split(", ");
+    let mut coordinate = |name: &str| {
+        let token = next_token(line, &mut coordinates, &format!("`{}=`", name))?;
+        let value = token
+            .strip_prefix(name)
+            .and_then(|value| value.strip_prefix('='))
+            .ok_or_else(|| ParseError::new(line, token, format!("expected `{}=`", name)))?;
+        parse_token(line, value)
+    };
+
+    Ok(Point {
+        x: coordinate("x")?,
+        y: coordinate("y")?,
+    })
+}
+
+/// Parse `row=R, limit=L`
+fn parse_parameters(line: &str) -> Result<(isize, isize), ParseError> {
+    let mut parameters = line.split(", ");
+    let mut parameter = |name: &str| {
+        let token = next_token(line, &mut parameters, &format!("`{}=`", name))?;
+        let value = token
+            .strip_prefix(name)
+            .and_then(|value| value.strip_prefix('='))
+            .ok_or_else(|| ParseError::new(line, token, format!("expected `{}=`", name)))?;
+        parse_token(line, value)
+    };
+
+    Ok((parameter("row")?, parameter("limit")?))
+}
+
+// Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+impl FromStr for Field {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut sensors = Vec::new();
+        let mut min_sensing = Point::default();
+        let mut max_sensing = Point::default();
+        let (mut scanned_row, mut search_limit) = (SCANNED_ROW, SEARCH_LIMIT);
+
+        for (i, line) in s.lines().enumerate() {
+            if i == 0 && line.starts_with("row=") {
+                (scanned_row, search_limit) =
+                    parse_parameters(line).map_err(|e| e.within(s, line))?;
+                continue;
+            }
+
+            let mut elements = line.split(": ");
+            let sensor = next_token(line, &mut elements, "a sensor")
+                .and_then(|sensor| parse_position(line, sensor, "Sensor at "))
+                .map_err(|e| e.within(s, line))?;
+            let beacon = next_token(line, &mut elements, "a beacon")
+                .and_then(|beacon| parse_position(line, beacon, "closest beacon is at "))
+                .map_err(|e| e.within(s, line))?;
+
+            let sensing_distance = sensor.manhattan(beacon);
+
+            sensors.push(Sensor {
+                position: sensor,
+                sensing_distance,
+                beacon,
+            });
+
+            if sensor.x - sensing_distance < min_sensing.x {
+                min_sensing.x = sensor.x - sensing_distance;
+            }
+            if sensor.y - sensing_distance < min_sensing.y {
+                min_sensing.y = sensor.y - sensing_distance;
+            }
+            if sensor.x + sensing_distance > max_sensing.x {
+                max_sensing.x = sensor.x + sensing_distance;
+            }
+            if sensor.y + sensing_distance > max_sensing.y {
+                max_sensing.y = sensor.y + sensing_distance;
+            }
+        }
+
+        Ok(Field {
+            sensors,
+            min_sensing,
+            max_sensing,
+            scanned_row,
+            search_limit,
+        })
+    }
+}
+
+impl Field {
+    pub fn is_sensed(&self, target: Point) -> bool {
+        self.sensors.iter().any(|sensor| sensor.can_sense(target))
+    }
+
+    pub fn border_search(&self, limit: isize) -> Option<Point> {
+        for y in 0..=limit {
+            for sensor in &self.sensors {
+                let x1 = sensor.position.x
+                    - (sensor.sensing_distance - (sensor.position.y - y).abs())
+                    - 1;
+                let x2 = sensor.position.x
+                    + (sensor.sensing_distance - (sensor.position.y - y).abs())
+                    + 1;
+
+                if (0..=limit).contains(&x1) && !self.is_sensed(Point { x: x1, y }) {
+                    return Some(Point { x: x1, y });
+                }
+                if (0..=limit).contains(&x2) && !self.is_sensed(Point { x: x2, y }) {
+                    return Some(Point { x: x2, y });
+                }
+            }
+        }
+        None
+    }
+}
+
+/// Row scanned in part 1
+pub const SCANNED_ROW: isize = 2000000;
+/// Maximum coordinate of the distress beacon in part 2
+pub const SEARCH_LIMIT: isize = 4000000;
+/// Multiplier of the x coordinate in the tuning frequency
+pub const TUNING_MULTIPLIER: isize = 4000000;
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+
+    type Input = Field;
+    type Answer1 = usize;
+    type Answer2 = isize;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: include_str!("../example"),
+        part1: Some("26"),
+        part2: Some("56000011"),
+    }];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.parse()
+    }
+
+    fn part1(field: &Self::Input) -> Result<Self::Answer1> {
+        let row = field.scanned_row;
+        let n_scanned = (field.min_sensing.x..=field.max_sensing.x)
+            .filter(|x| field.is_sensed(Point { x: *x, y: row }))
+            .count();
+        if n_scanned == 0 {
+            return Err(Error::msg(format!("No sensor reaches row {}", row)));
+        }
+
+        // Beacons are sensed, but they are where a beacon is
+        let mut beacons: Vec<isize> = field
+            .sensors
+            .iter()
+            .filter(|sensor| sensor.beacon.y == row)
+            .map(|sensor| sensor.beacon.x)
+            .collect();
+        beacons.sort_unstable();
+        beacons.dedup();
+
+        Ok(n_scanned - beacons.len())
+    }
+
+    fn part2(field: &Self::Input) -> Result<Self::Answer2> {
+        let beacon = field
+            .border_search(field.search_limit)
+            .ok_or_else(|| Error::msg("There is no room for the distress beacon"))?;
+
+        Ok(beacon.x * TUNING_MULTIPLIER + beacon.y)
+    }
+}
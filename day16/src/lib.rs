@@ -0,0 +1,217 @@
+// https://adventofcode.com/2022/day/16
+
+use aoc_common::{bfs, parse_lines, parse_token, Example, ParseError, Result, Solution, Unsolved};
+use regex::Regex;
+use std::{collections::BinaryHeap, str::FromStr};
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct Valve {
+    name: String,
+    rate: usize,
+    next: Vec<String>,
+    opened: bool,
+}
+
+impl FromStr for Valve {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let re = Regex::new(r"^Valve ([A-Z]{2}) has flow rate=(\d+); tunnels? leads? to valves? ((?:(?:[A-Z]{2}),? ?)+)$").unwrap();
+
+        let m = re.captures(s).ok_or_else(|| {
+            ParseError::new(
+                s,
+                s,
+                "expected `Valve XX has flow rate=N; tunnels lead to valves YY, ZZ`",
+            )
+        })?;
+
+        let name = m[1].to_owned();
+        let rate = parse_token(s, m.get(2).map_or(&s[..0], |rate| rate.as_str()))?;
+        let next = m[3].split(", ").map(|n| n.to_owned()).collect();
+
+        Ok(Valve {
+            name,
+            rate,
+            next,
+            opened: false,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Cave {
+    valves: Vec<Valve>,
+    distances: Vec<Vec<usize>>, // Matrix of distances[from][to] valves
+}
+
+#[derive(Debug, Clone)]
+pub struct State {
+    valves_opened: Vec<bool>,
+    current_valve: usize,
+    released: usize,
+    time: usize,
+}
+
+impl PartialEq for State {
+    fn eq(&self, other: &Self) -> bool {
+        self.score() == other.score()
+    }
+}
+
+impl Eq for State {}
+
+impl PartialOrd for State {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for State {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.score().total_cmp(&other.score())
+    }
+}
+
+impl State {
+    fn score(&self) -> f64 {
+        self.released as f64 / self.time as f64
+    }
+
+    fn travel(&mut self, to_valve: usize, travel_time: usize, valves: &[Valve]) {
+        self.released += self.release_rate(valves) * (travel_time + 1);
+        self.current_valve = to_valve;
+        self.time += travel_time + 1; // We need 1 minute to open the valve!
+        self.valves_opened[to_valve] = true;
+    }
+
+    fn release_rate(&self, valves: &[Valve]) -> usize {
+        valves
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| self.valves_opened[*i])
+            .map(|(_, v)| v.rate)
+            .sum()
+    }
+}
+
+fn find_valve_distances(from: usize, valves: &[Valve]) -> Vec<usize> {
+    let tunnels = |&valve_id: &usize| {
+        valves[valve_id]
+            .next
+            .iter()
+            .map(|next| valves.iter().position(|v| &v.name == next).unwrap())
+    };
+    let paths = bfs(from, tunnels, |_| false);
+
+    (0..valves.len())
+        .map(|valve_id| paths.cost(&valve_id).unwrap_or(0))
+        .collect()
+}
+
+impl FromStr for Cave {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let valves: Vec<Valve> = parse_lines(s, str::parse)?;
+
+        // Every tunnel has to lead somewhere and the search starts from AA
+        for (line, valve) in s.lines().zip(valves.iter()) {
+            for next in valve.next.iter() {
+                if !valves.iter().any(|v| &v.name == next) {
+                    let (i, _) = line.rmatch_indices(next.as_str()).next().unwrap_or((0, ""));
+                    let token = &line[i..i + next.len()];
+                    return Err(ParseError::new(s, token, "no valve with this name"));
+                }
+            }
+        }
+        if !valves.iter().any(|v| v.name == "AA") {
+            return Err(ParseError::missing(s, "expected the valve AA"));
+        }
+
+        let distances = (0..valves.len())
+            .map(|v| find_valve_distances(v, &valves))
+            .collect();
+
+        Ok(Cave { valves, distances })
+    }
+}
+
+impl Cave {
+    pub fn find_max_release(&self, run_time: usize) -> usize {
+        let aa_valve = self.valves.iter().position(|v| v.name == "AA").unwrap();
+
+        let mut states: BinaryHeap<State> = [State {
+            valves_opened: vec![false; self.valves.len()],
+            current_valve: aa_valve,
+            released: 0,
+            time: 0,
+        }]
+        .iter()
+        .cloned()
+        .collect();
+
+        let mut max_release = 0;
+        let mut max_time = 0;
+
+        while !states.is_empty() {
+            states = states
+                .iter()
+                .flat_map(|state| {
+                    // update max_release with this state at run time
+                    let this_max = ((run_time - state.time) * state.release_rate(&self.valves))
+                        + state.released;
+                    max_release = max_release.max(this_max);
+                    max_time = max_time.max(state.time);
+
+                    // Visit all possible valves as long as we do not go over the runtime
+                    state
+                        .valves_opened
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, opened)| !*opened)
+                        .map(|(valve_id, _)| {
+                            let mut state = state.clone();
+
+                            let travel_time = self.distances[state.current_valve][valve_id];
+                            state.travel(valve_id, travel_time, &self.valves);
+
+                            state
+                        })
+                        .filter(|state| state.time < run_time)
+                        .filter(|state| self.valves[state.current_valve].rate > 0)
+                })
+                .collect();
+        }
+
+        max_release
+    }
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+
+    type Input = Cave;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: include_str!("../example"),
+        part1: Some("1651"),
+        part2: None,
+    }];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.parse()
+    }
+
+    fn part1(cave: &Self::Input) -> Result<Self::Answer1> {
+        Ok(cave.find_max_release(30))
+    }
+
+    fn part2(_cave: &Self::Input) -> Result<Self::Answer2> {
+        Err(Unsolved.into())
+    }
+}
@@ -0,0 +1,342 @@
+// https://adventofcode.com/2022/day/17
+
+use aoc_common::{
+    char_at, Bounds, Example, Frame, Glyph, Grid, ParseError, Part, Point, Render, Result,
+    Simulation, Solution, SparseGrid, Unsolved,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Shape {
+    #[default]
+    HBar,
+    Plus,
+    RL,
+    VBar,
+    Square,
+}
+
+impl Iterator for Shape {
+    type Item = Shape;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let prev = *self;
+        *self = match self {
+            Self::HBar => Self::Plus,
+            Self::Plus => Self::RL,
+            Self::RL => Self::VBar,
+            Self::VBar => Self::Square,
+            Self::Square => Self::HBar,
+        };
+
+        Some(prev)
+    }
+}
+
+impl Shape {
+    pub fn get_points(&self) -> Vec<Point> {
+        match self {
+            Shape::HBar => vec![
+                Point::new(0, 0),
+                Point::new(1, 0),
+                Point::new(2, 0),
+                Point::new(3, 0),
+            ],
+            Shape::Plus => vec![
+                Point::new(0, 1),
+                Point::new(1, 1),
+                Point::new(2, 1),
+                Point::new(1, 0),
+                Point::new(1, 2),
+            ],
+            Shape::RL => vec![
+                Point::new(0, 0),
+                Point::new(1, 0),
+                Point::new(2, 0),
+                Point::new(2, 1),
+                Point::new(2, 2),
+            ],
+            Shape::VBar => vec![
+                Point::new(0, 0),
+                Point::new(0, 1),
+                Point::new(0, 2),
+                Point::new(0, 3),
+            ],
+            Shape::Square => vec![
+                Point::new(0, 0),
+                Point::new(1, 0),
+                Point::new(1, 1),
+                Point::new(0, 1),
+            ],
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Rock {
+    pub shape: Shape,
+    pub position: Point,
+}
+impl Rock {
+    pub fn does_collide(&self, cave: &Cave) -> bool {
+        self.shape
+            .get_points()
+            .iter()
+            .map(|p| *p + self.position)
+            .any(|point| !matches!(cave.get_cell_at(point), Cell::Air))
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub enum Cell {
+    #[default]
+    Air,
+    Wall,
+    Rock,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum WindDirection {
+    Left,
+    Right,
+}
+
+impl From<WindDirection> for Point {
+    fn from(direction: WindDirection) -> Point {
+        match direction {
+            WindDirection::Left => Point::LEFT,
+            WindDirection::Right => Point::RIGHT,
+        }
+    }
+}
+
+impl TryFrom<char> for WindDirection {
+    type Error = char;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '<' => Ok(WindDirection::Left),
+            '>' => Ok(WindDirection::Right),
+            _ => Err(value),
+        }
+    }
+}
+
+pub struct Wind {
+    directions: Vec<WindDirection>,
+    current: usize,
+}
+
+impl Wind {
+    pub fn new(directions: Vec<WindDirection>) -> Self {
+        Self {
+            directions,
+            current: 0,
+        }
+    }
+}
+
+impl Iterator for Wind {
+    type Item = WindDirection;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let dir = self.directions[self.current];
+        self.current = (self.current + 1) % self.directions.len();
+        Some(dir)
+    }
+}
+
+#[derive(Default)]
+pub struct Cave {
+    cave: SparseGrid<Cell>,
+}
+
+impl Cave {
+    pub fn get_cell_at(&self, pos: Point) -> Cell {
+        match pos {
+            Point { x, y: _ } if x < 0 => Cell::Wall,
+            Point { x, y: _ } if x >= 7 => Cell::Wall,
+            Point { x: _, y } if y < -0 => Cell::Wall,
+            _ => self.cave.get(pos).cloned().unwrap_or_default(),
+        }
+    }
+
+    pub fn get_top(&self) -> isize {
+        self.cave
+            .iter()
+            .filter(|(_, v)| !matches!(v, Cell::Air))
+            .map(|(k, _)| k.y)
+            .max()
+            .unwrap_or(-1)
+    }
+
+    pub fn put_rock(&mut self, rock: &Rock) {
+        for point in rock.shape.get_points() {
+            self.cave.insert(rock.position + point, Cell::Rock);
+        }
+    }
+}
+
+/// Rocks falling in the cave, each step is one push of the wind and one fall
+pub struct RockFall {
+    pub cave: Cave,
+    wind: Wind,
+    shape: Shape,
+    /// Rock falling, `None` once they have all come to rest
+    rock: Option<Rock>,
+    rocks_left: usize,
+    dropped: usize,
+}
+
+impl RockFall {
+    pub fn new(wind_directions: &[WindDirection], n_rocks: usize) -> Self {
+        let mut fall = RockFall {
+            cave: Cave::default(),
+            wind: Wind::new(wind_directions.to_vec()),
+            shape: Shape::default(),
+            rock: None,
+            rocks_left: n_rocks,
+            dropped: 0,
+        };
+        fall.next_rock();
+        fall
+    }
+
+    fn next_rock(&mut self) {
+        self.rock = (self.rocks_left > 0).then(|| Rock {
+            position: Point {
+                x: 2,
+                y: self.cave.get_top() + 4,
+            },
+            shape: self.shape.next().unwrap(),
+        });
+        self.rocks_left = self.rocks_left.saturating_sub(1);
+    }
+}
+
+impl Simulation for RockFall {
+    fn step(&mut self) -> bool {
+        let Some(mut rock) = self.rock.clone() else {
+            return false;
+        };
+
+        // First the wind
+        let mut test_rock = rock.clone();
+        let wind_direction = self.wind.next().unwrap();
+        test_rock.position += wind_direction.into();
+        if !test_rock.does_collide(&self.cave) {
+            rock = test_rock;
+        }
+
+        // Then the bottom movement
+        let mut test_rock = rock.clone();
+        test_rock.position += Point::new(0, -1);
+        if !test_rock.does_collide(&self.cave) {
+            self.rock = Some(test_rock);
+        } else {
+            // If we cannot get down, the rock is stable in the cave
+            // Put it there and drop the next rock!
+            self.cave.put_rock(&rock);
+            self.dropped += 1;
+            self.next_rock();
+        }
+
+        true
+    }
+}
+
+/// The tower from the top down to the floor, the falling rock is `@`
+impl Render for RockFall {
+    fn render(&self) -> Frame {
+        let falling: Vec<Point> = match &self.rock {
+            Some(rock) => rock
+                .shape
+                .get_points()
+                .into_iter()
+                .map(|p| p + rock.position)
+                .collect(),
+            None => Vec::new(),
+        };
+        let top = falling
+            .iter()
+            .map(|p| p.y)
+            .max()
+            .unwrap_or(0)
+            .max(self.cave.get_top() + 3);
+
+        let bounds = Bounds {
+            min: Point::new(-1, 0),
+            max: Point::new(7, top + 1),
+        };
+        Frame::draw(bounds, |p| {
+            let p = Point::new(p.x, top - p.y);
+            let glyph = match (p.y, p.x) {
+                (-1, -1 | 7) => '+',
+                (-1, _) => '-',
+                _ if falling.contains(&p) => return Glyph::colored('@', [255, 200, 0]),
+                _ => match self.cave.get_cell_at(p) {
+                    Cell::Wall => '|',
+                    Cell::Air => '.',
+                    Cell::Rock => '#',
+                },
+            };
+            glyph.into()
+        })
+        .with_caption(format!(
+            "{} rocks dropped, the tower is {} high",
+            self.dropped,
+            self.cave.get_top() + 1
+        ))
+    }
+}
+
+/// Drop rocks in the cave and return the cave once they all came to rest
+pub fn drop_rocks(wind_directions: &[WindDirection], n_rocks: usize) -> Cave {
+    let mut fall = RockFall::new(wind_directions, n_rocks);
+    while fall.step() {}
+    fall.cave
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+
+    type Input = Vec<WindDirection>;
+    type Answer1 = isize;
+    type Answer2 = isize;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: include_str!("../example"),
+        part1: Some("3068"),
+        part2: None,
+    }];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let wind = input.trim_end();
+        if wind.is_empty() {
+            return Err(ParseError::missing(wind, "expected the wind directions"));
+        }
+
+        wind.char_indices()
+            .map(|(i, c)| {
+                WindDirection::try_from(c)
+                    .map_err(|_| ParseError::new(input, char_at(wind, i), "expected `<` or `>`"))
+            })
+            .collect()
+    }
+
+    fn part1(wind_directions: &Self::Input) -> Result<Self::Answer1> {
+        Ok(drop_rocks(wind_directions, 2022).get_top() + 1)
+    }
+
+    fn part2(_wind_directions: &Self::Input) -> Result<Self::Answer2> {
+        Err(Unsolved.into())
+    }
+
+    fn simulation(wind_directions: &Self::Input, part: Part) -> Option<Box<dyn Simulation>> {
+        match part {
+            Part::One => Some(Box::new(RockFall::new(wind_directions, 2022))),
+            Part::Two => None,
+        }
+    }
+}
@@ -0,0 +1,213 @@
+// https://adventofcode.com/2022/day/18
+
+use std::{collections::HashMap, str::FromStr};
+
+use aoc_common::{
+    next_token, parse_lines, parse_token, Bfs, Bounds, Example, Frame, Glyph, ParseError, Part,
+    Point as Point2, Render, Result, Simulation, Solution,
+};
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub struct Point {
+    pub x: isize,
+    pub y: isize,
+    pub z: isize,
+}
+impl Point {
+    /// Return true if the other point is touching us
+    pub fn adjacent(&self, other: &&Point) -> bool {
+        // dbg!((self.x - other.x).abs());
+        (self.x == other.x && self.y == other.y && (self.z - other.z).abs() <= 1)
+            || (self.y == other.y && self.z == other.z && (self.x - other.x).abs() <= 1)
+            || (self.z == other.z && self.x == other.x && (self.y - other.y).abs() <= 1)
+    }
+
+    pub fn new(x: isize, y: isize, z: isize) -> Self {
+        Point { x, y, z }
+    }
+}
+
+impl FromStr for Point {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut elements = s.split(',');
+        Ok(Point {
+            x: parse_token(s, next_token(s, &mut elements, "x")?)?,
+            y: parse_token(s, next_token(s, &mut elements, "y")?)?,
+            z: parse_token(s, next_token(s, &mut elements, "z")?)?,
+        })
+    }
+}
+
+#[derive(Debug, Default, Hash, PartialEq, Eq, Clone, Copy)]
+pub enum Cell {
+    #[default]
+    Air,
+    Steam,
+    Rock,
+}
+
+/// The outside of the droplet filling up with steam, one cube of air per step
+pub struct SteamFill {
+    pub map: HashMap<Point, Cell>,
+    search: Bfs<Point>,
+    max: Point,
+}
+
+impl SteamFill {
+    pub fn new(cubes: &[Point]) -> Self {
+        let max = Point {
+            x: cubes.iter().map(|p| p.x).max().unwrap(),
+            y: cubes.iter().map(|p| p.y).max().unwrap(),
+            z: cubes.iter().map(|p| p.z).max().unwrap(),
+        };
+        let map = cubes.iter().map(|&cube| (cube, Cell::Rock)).collect();
+
+        SteamFill {
+            map,
+            search: Bfs::new(Point::new(0, 0, 0)),
+            max,
+        }
+    }
+
+    pub fn steam(&self) -> usize {
+        self.map
+            .values()
+            .filter(|cell| matches!(cell, Cell::Steam))
+            .count()
+    }
+}
+
+impl Simulation for SteamFill {
+    fn step(&mut self) -> bool {
+        let SteamFill { map, search, max } = self;
+        let mut air_around = |current: &Point| {
+            let current = *current;
+            [
+                (-1, 0, 0),
+                (1, 0, 0),
+                (0, -1, 0),
+                (0, 1, 0),
+                (0, 0, -1),
+                (0, 0, 1),
+            ]
+            .into_iter()
+            .map(move |(x, y, z)| Point {
+                x: current.x + x,
+                y: current.y + y,
+                z: current.z + z,
+            })
+            .filter(|neighbor| {
+                (-1..=max.x + 1).contains(&neighbor.x)
+                    && (0..=max.y + 1).contains(&neighbor.y)
+                    && (0..=max.z + 1).contains(&neighbor.z)
+                    && matches!(map.get(neighbor).cloned().unwrap_or_default(), Cell::Air)
+            })
+            .collect::<Vec<_>>()
+        };
+
+        match search.step(&mut air_around) {
+            Some(point) => {
+                map.entry(point).or_insert(Cell::Steam);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+/// Slices of the droplet from the front to the back, one under the other
+impl Render for SteamFill {
+    fn render(&self) -> Frame {
+        let slice_height = self.max.y + 2;
+        let bounds = Bounds {
+            min: Point2::new(-1, 0),
+            max: Point2::new(self.max.x + 1, (self.max.z + 2) * (slice_height + 1) - 2),
+        };
+        Frame::draw(bounds, |p| {
+            let (z, y) = (p.y / (slice_height + 1), p.y % (slice_height + 1));
+            if y == slice_height {
+                return '-'.into();
+            }
+            match self.map.get(&Point::new(p.x, y, z)) {
+                None | Some(Cell::Air) => '.'.into(),
+                Some(Cell::Steam) => Glyph::colored('~', [120, 180, 255]),
+                Some(Cell::Rock) => '#'.into(),
+            }
+        })
+        .with_caption(format!("{} cubes of steam", self.steam()))
+    }
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+
+    type Input = Vec<Point>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: include_str!("../example"),
+        part1: Some("64"),
+        part2: Some("58"),
+    }];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let cubes = parse_lines(input, str::parse)?;
+        if cubes.is_empty() {
+            return Err(ParseError::missing(input, "expected a cube"));
+        }
+
+        Ok(cubes)
+    }
+
+    fn part1(cubes: &Self::Input) -> Result<Self::Answer1> {
+        let mut exposed_sides = 0;
+
+        for cube in cubes {
+            // Test every of the 6 face for being covered by another cube
+            let covered = cubes
+                .iter()
+                .filter(|other| *cube != **other && cube.adjacent(other))
+                .count();
+
+            exposed_sides += 6 - covered;
+        }
+
+        Ok(exposed_sides)
+    }
+
+    fn part2(cubes: &Self::Input) -> Result<Self::Answer2> {
+        // Part2: To isolate pockets, we create a 3D MAP then fill the outdide with steam
+        // The new result is the number of adjacent rock to steam
+        let mut fill = SteamFill::new(cubes);
+        while fill.step() {}
+
+        let map = fill.map;
+
+        // Find all the faces that touches steam
+        let exposed_to_steam: usize = map
+            .iter()
+            .filter(|(_, cell)| matches!(cell, Cell::Rock))
+            .map(|(cube, _)| {
+                // println!("Point: {:?}", &cube);
+                map.iter()
+                    // .inspect(|i| { dbg!(i); })
+                    .filter(|(point, cell)| matches!(cell, Cell::Steam) && point.adjacent(&cube))
+                    .count()
+            })
+            .sum();
+
+        Ok(exposed_to_steam)
+    }
+
+    fn simulation(cubes: &Self::Input, part: Part) -> Option<Box<dyn Simulation>> {
+        match part {
+            Part::One => None,
+            Part::Two => Some(Box::new(SteamFill::new(cubes))),
+        }
+    }
+}
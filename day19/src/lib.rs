@@ -0,0 +1,285 @@
+// https://adventofcode.com/2022/day/19
+
+use std::{
+    collections::BTreeSet,
+    ops::{Sub, SubAssign},
+    str::FromStr,
+};
+
+use aoc_common::{next_token, parse_lines, parse_token, Example, ParseError, Result, Solution};
+
+#[derive(Debug)]
+pub struct Blueprint {
+    pub ore_bot: Stash,
+    pub clay_bot: Stash,
+    pub obsidian_bot: Stash,
+    pub geode_bot: Stash,
+}
+
+impl FromStr for Blueprint {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (_, robots) = s
+            .split_once(": ")
+            .ok_or_else(|| ParseError::new(s, s, "expected `Blueprint N: `"))?;
+        let mut elements = robots.split('.');
+        let mut robot = |name: &str| {
+            let what = format!("the cost of the {} robot", name);
+            let sentence = next_token(s, &mut elements, &what)?;
+            let cost = sentence
+                .split_once("costs ")
+                .map(|(_, cost)| cost)
+                .ok_or_else(|| ParseError::new(s, sentence, format!("expected {}", what)))?;
+            Stash::from_str(cost).map_err(|e| e.within(s, cost))
+        };
+
+        Ok(Blueprint {
+            ore_bot: robot("ore")?,
+            clay_bot: robot("clay")?,
+            obsidian_bot: robot("obsidian")?,
+            geode_bot: robot("geode")?,
+        })
+    }
+}
+
+impl Blueprint {
+    fn can_build_ore_bot(&self, stash: &Stash) -> bool {
+        stash.ore >= self.ore_bot.ore
+            && stash.clay >= self.ore_bot.clay
+            && stash.obsidian >= self.ore_bot.obsidian
+            && stash.geode >= self.ore_bot.geode
+    }
+
+    fn can_build_clay_bot(&self, stash: &Stash) -> bool {
+        stash.ore >= self.clay_bot.ore
+            && stash.clay >= self.clay_bot.clay
+            && stash.obsidian >= self.clay_bot.obsidian
+            && stash.geode >= self.clay_bot.geode
+    }
+
+    fn can_build_obsidian_bot(&self, stash: &Stash) -> bool {
+        stash.ore >= self.obsidian_bot.ore
+            && stash.clay >= self.obsidian_bot.clay
+            && stash.obsidian >= self.obsidian_bot.obsidian
+            && stash.geode >= self.obsidian_bot.geode
+    }
+
+    fn can_build_geode_bot(&self, stash: &Stash) -> bool {
+        stash.ore >= self.geode_bot.ore
+            && stash.clay >= self.geode_bot.clay
+            && stash.obsidian >= self.geode_bot.obsidian
+            && stash.geode >= self.geode_bot.geode
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Stash {
+    pub ore: usize,
+    pub clay: usize,
+    pub obsidian: usize,
+    pub geode: usize,
+}
+
+impl FromStr for Stash {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut stash = Stash::default();
+        for item in s.split(" and ") {
+            let mut item = item.split(' ');
+            let quantity = parse_token(s, next_token(s, &mut item, "a quantity")?)?;
+            let element = next_token(s, &mut item, "a material")?;
+            match element {
+                "ore" => stash.ore = quantity,
+                "clay" => stash.clay = quantity,
+                "obsidian" => stash.obsidian = quantity,
+                "geode" => stash.geode = quantity,
+                _ => {
+                    return Err(ParseError::new(
+                        s,
+                        element,
+                        "expected ore, clay, obsidian or geode",
+                    ))
+                }
+            }
+        }
+        Ok(stash)
+    }
+}
+
+impl Sub<Stash> for Stash {
+    type Output = Stash;
+
+    fn sub(self, rhs: Stash) -> Self::Output {
+        Stash {
+            ore: self.ore - rhs.ore,
+            clay: self.clay - rhs.clay,
+            obsidian: self.obsidian - rhs.obsidian,
+            geode: self.geode - rhs.geode,
+        }
+    }
+}
+
+impl SubAssign for Stash {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct State {
+    ore_bot: usize,
+    clay_bot: usize,
+    obsidian_bot: usize,
+    geode_bot: usize,
+    stash: Stash,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self {
+            ore_bot: 1,
+            clay_bot: Default::default(),
+            obsidian_bot: Default::default(),
+            geode_bot: Default::default(),
+            stash: Default::default(),
+        }
+    }
+}
+
+impl State {
+    fn run_bots(&mut self) {
+        self.stash.ore += self.ore_bot;
+        self.stash.clay += self.clay_bot;
+        self.stash.obsidian += self.obsidian_bot;
+        self.stash.geode += self.geode_bot;
+    }
+}
+
+pub fn test_blueprint(blueprint: &Blueprint, runtime: usize) -> usize {
+    // println!("\nRunning bluepring:");
+    // dbg!(blueprint);
+    // let mut to_visit = VecDeque::new();
+    let mut to_visit = BTreeSet::new();
+    to_visit.insert(State::default());
+
+    for _round in 0..runtime {
+        // printroundln!("Round {}, {} states to inspect", round, to_visit.len());
+        let mut new_states = BTreeSet::new();
+        // Empty the queue once on each round
+        for mut state in to_visit.iter().cloned() {
+            // First, run the bots
+
+            // dbg!(&state);
+
+            // Now the branches, try to build all the possible bots and push the resulting state in the queue
+            if blueprint.can_build_geode_bot(&state.stash) {
+                let mut state = state.clone();
+                state.stash -= blueprint.geode_bot;
+
+                state.run_bots();
+
+                state.geode_bot += 1;
+                new_states.insert(state);
+            }
+            if blueprint.can_build_obsidian_bot(&state.stash) {
+                let mut state = state.clone();
+                state.stash -= blueprint.obsidian_bot;
+
+                state.run_bots();
+
+                state.obsidian_bot += 1;
+                new_states.insert(state);
+            }
+
+            if blueprint.can_build_ore_bot(&state.stash) {
+                let mut state = state.clone();
+                state.stash -= blueprint.ore_bot;
+
+                state.run_bots();
+
+                state.ore_bot += 1;
+                new_states.insert(state);
+            }
+
+            if blueprint.can_build_clay_bot(&state.stash) {
+                let mut state = state.clone();
+                state.stash -= blueprint.clay_bot;
+
+                state.run_bots();
+
+                state.clay_bot += 1;
+                new_states.insert(state);
+            }
+
+            // Building nothing is always a possible outcome
+            state.run_bots();
+            new_states.insert(state);
+        }
+
+        // dbg!(&new_states.first());
+
+        let max_geodes = to_visit
+            .iter()
+            .map(|state| state.stash.geode)
+            .max()
+            .unwrap();
+
+        to_visit = new_states
+            .iter()
+            .filter(|state| state.stash.geode >= 2.max(max_geodes) - 2)
+            .cloned()
+            .collect();
+        // println!("{}: Ammount of geodes: {}", round, max_geodes,);
+        // println!("Pruned: {} states", new_states.len() - to_visit.len());
+    }
+
+    // Now contains all the states after 24 round
+    // Let find the max amount of geode reached
+    to_visit
+        .iter()
+        .map(|state| state.stash.geode)
+        .max()
+        .unwrap()
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+
+    type Input = Vec<Blueprint>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: include_str!("../example"),
+        part1: Some("33"),
+        part2: Some("3472"),
+    }];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input, str::parse)
+    }
+
+    fn part1(blueprints: &Self::Input) -> Result<Self::Answer1> {
+        let quality_sum = blueprints
+            .iter()
+            .enumerate()
+            .map(|(n, blueprint)| (n + 1) * test_blueprint(blueprint, 24))
+            .sum();
+
+        Ok(quality_sum)
+    }
+
+    fn part2(blueprints: &Self::Input) -> Result<Self::Answer2> {
+        let quality_product = blueprints
+            .iter()
+            .take(3)
+            .map(|blueprint| test_blueprint(blueprint, 32))
+            .product();
+
+        Ok(quality_product)
+    }
+}
@@ -0,0 +1,712 @@
+// https://adventofcode.com/2022/day/2
+
+use std::{cmp::Reverse, str::FromStr};
+
+use anyhow::anyhow;
+use aoc_common::{
+    keyword, next_token, parse_lines, Bounds, Example, Frame, Glyph, ParseError, Part, Point,
+    Render, Result, Simulation, Solution,
+};
+
+/// Shape a player shows, its index in the moves of the [`Rules`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Shape(pub usize);
+
+impl Shape {
+    pub const ROCK: Shape = Shape(0);
+    pub const PAPER: Shape = Shape(1);
+    pub const SCISSORS: Shape = Shape(2);
+}
+
+impl FromStr for Shape {
+    type Err = ParseError;
+
+    fn from_str(raw: &str) -> Result<Self, Self::Err> {
+        match raw {
+            "A" => Ok(Shape::ROCK),
+            "B" => Ok(Shape::PAPER),
+            "C" => Ok(Shape::SCISSORS),
+            _ => Err(ParseError::new(raw, raw, "expected A, B or C")),
+        }
+    }
+}
+
+/// Rules of Rock Paper Scissors Lizard Spock
+pub const RPSLS: &str = "\
+moves Rock Paper Scissors Lizard Spock
+Scissors beats Paper Lizard
+Paper beats Rock Spock
+Rock beats Lizard Scissors
+Lizard beats Spock Paper
+Spock beats Scissors Rock
+";
+
+/// Moves of a game and which move beats which
+///
+/// Every two moves have a winner. A shape scores its position in the moves,
+/// from 1.
+///
+/// The rules are written as the list of moves, then what each move beats:
+///
+/// ```text
+/// # Comments start with a `#`
+/// moves Rock Paper Scissors
+/// Rock beats Scissors
+/// Paper beats Rock
+/// Scissors beats Paper
+/// ```
+///
+/// Without the `beats` lines, the moves form a cyclic tournament: each move
+/// beats the half of the moves before it, wrapping around.
+///
+/// ```
+/// use day2::{Outcome, Rules};
+///
+/// let rules = Rules::rpsls();
+/// let [spock, rock] = ["Spock", "Rock"].map(|name| rules.shape(name).unwrap());
+/// assert_eq!(rules.outcome(spock, rock), Outcome::Win);
+/// assert_eq!(rules.choose(spock, Outcome::Win), rules.shape("Lizard"));
+///
+/// let cyclic = Rules::cyclic(["Rock", "Spock", "Paper", "Lizard", "Scissors"]).unwrap();
+/// let [spock, rock] = ["Spock", "Rock"].map(|name| cyclic.shape(name).unwrap());
+/// assert_eq!(cyclic.outcome(spock, rock), Outcome::Win);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Rules {
+    names: Vec<String>,
+    /// `beats[a][b]` when `a` beats `b`
+    beats: Vec<Vec<bool>>,
+}
+
+impl Default for Rules {
+    /// Rock Paper Scissors
+    fn default() -> Self {
+        Rules::cyclic(["Rock", "Paper", "Scissors"]).unwrap()
+    }
+}
+
+impl Rules {
+    /// Cyclic tournament of the moves, `None` unless there is an odd number of them
+    pub fn cyclic<S: Into<String>>(names: impl IntoIterator<Item = S>) -> Option<Self> {
+        let names: Vec<String> = names.into_iter().map(Into::into).collect();
+        let n = names.len();
+        if n.is_multiple_of(2) {
+            return None;
+        }
+
+        let beats = (0..n)
+            .map(|a| {
+                (0..n)
+                    .map(|b| (1..=n / 2).contains(&((a + n - b) % n)))
+                    .collect()
+            })
+            .collect();
+        Some(Rules { names, beats })
+    }
+
+    pub fn rpsls() -> Self {
+        RPSLS.parse().expect("The RPSLS rules are valid")
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    pub fn shapes(&self) -> impl Iterator<Item = Shape> {
+        (0..self.len()).map(Shape)
+    }
+
+    pub fn name(&self, shape: Shape) -> &str {
+        &self.names[shape.0]
+    }
+
+    pub fn shape(&self, name: &str) -> Option<Shape> {
+        self.names.iter().position(|n| n == name).map(Shape)
+    }
+
+    pub fn score(&self, shape: Shape) -> u64 {
+        shape.0 as u64 + 1
+    }
+
+    pub fn beats(&self, a: Shape, b: Shape) -> bool {
+        self.beats[a.0][b.0]
+    }
+
+    /// Outcome for the player showing `mine`
+    pub fn outcome(&self, mine: Shape, theire: Shape) -> Outcome {
+        if self.beats(mine, theire) {
+            Outcome::Win
+        } else if self.beats(theire, mine) {
+            Outcome::Loss
+        } else {
+            Outcome::Draw
+        }
+    }
+
+    /// Every shape reaching `outcome` against `theire`
+    pub fn responses(&self, theire: Shape, outcome: Outcome) -> impl Iterator<Item = Shape> + '_ {
+        self.shapes()
+            .filter(move |&mine| self.outcome(mine, theire) == outcome)
+    }
+
+    /// Highest scoring shape reaching `outcome` against `theire`
+    pub fn choose(&self, theire: Shape, outcome: Outcome) -> Option<Shape> {
+        self.responses(theire, outcome).max()
+    }
+}
+
+impl FromStr for Rules {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = s
+            .lines()
+            .filter(|line| !line.trim().is_empty() && !line.trim_start().starts_with('#'));
+
+        let moves = next_token(s, &mut lines, "the moves")?;
+        let mut tokens = moves.split_whitespace();
+        keyword(moves, &mut tokens, "moves").map_err(|e| e.within(s, moves))?;
+        let names: Vec<&str> = tokens.collect();
+        if names.is_empty() {
+            return Err(ParseError::missing(moves, "expected the moves").within(s, moves));
+        }
+        if let Some((_, name)) = names
+            .iter()
+            .enumerate()
+            .find(|(i, name)| names[..*i].contains(name))
+        {
+            return Err(ParseError::new(s, name, "expected each move once"));
+        }
+
+        let mut beats = vec![vec![false; names.len()]; names.len()];
+        let mut explicit = false;
+        for line in lines {
+            let shape = |name: &str| {
+                names
+                    .iter()
+                    .position(|n| *n == name)
+                    .ok_or_else(|| ParseError::new(s, name, "expected one of the moves"))
+            };
+
+            let mut tokens = line.split_whitespace();
+            let winner =
+                shape(next_token(line, &mut tokens, "a move").map_err(|e| e.within(s, line))?)?;
+            keyword(line, &mut tokens, "beats").map_err(|e| e.within(s, line))?;
+            for loser in tokens {
+                let index = shape(loser)?;
+                if index == winner || beats[index][winner] {
+                    return Err(ParseError::new(s, loser, "expected a move it can beat"));
+                }
+                beats[winner][index] = true;
+            }
+            explicit = true;
+        }
+
+        if !explicit {
+            return Rules::cyclic(names).ok_or_else(|| {
+                ParseError::missing(s, "expected an odd number of moves for a cyclic tournament")
+            });
+        }
+
+        for a in 0..names.len() {
+            for b in a + 1..names.len() {
+                if !beats[a][b] && !beats[b][a] {
+                    return Err(ParseError::missing(
+                        s,
+                        format!("expected {} or {} to beat the other", names[a], names[b]),
+                    ));
+                }
+            }
+        }
+
+        Ok(Rules {
+            names: names.into_iter().map(String::from).collect(),
+            beats,
+        })
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Outcome {
+    Loss = 0,
+    Draw = 3,
+    Win = 6,
+}
+
+impl Outcome {
+    pub const ALL: [Outcome; 3] = [Outcome::Loss, Outcome::Draw, Outcome::Win];
+}
+
+/// Second column of the strategy guide, its meaning depends on the part
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Column {
+    X = 0,
+    Y = 1,
+    Z = 2,
+}
+
+impl FromStr for Column {
+    type Err = ParseError;
+
+    fn from_str(raw: &str) -> Result<Self, Self::Err> {
+        match raw {
+            "X" => Ok(Column::X),
+            "Y" => Ok(Column::Y),
+            "Z" => Ok(Column::Z),
+            _ => Err(ParseError::new(raw, raw, "expected X, Y or Z")),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+pub struct Round {
+    pub mine: Shape,
+    pub theire: Shape,
+}
+
+impl Round {
+    pub fn play(&self, rules: &Rules) -> Outcome {
+        rules.outcome(self.mine, self.theire)
+    }
+
+    /// Round reaching `outcome`, `None` when no shape does
+    pub fn new_from_indended_outcome(
+        rules: &Rules,
+        theire: Shape,
+        outcome: Outcome,
+    ) -> Option<Self> {
+        let mine = rules.choose(theire, outcome)?;
+        Some(Round { mine, theire })
+    }
+
+    pub fn score(&self, rules: &Rules) -> u64 {
+        rules.score(self.mine) + self.play(rules) as u64
+    }
+}
+
+/// How the second column of the strategy guide is read
+pub trait GuideInterpretation {
+    /// Round to play against `theire`, `None` when the rules do not allow one
+    fn round(&self, rules: &Rules, theire: Shape, column: Column) -> Option<Round>;
+
+    /// What each column stands for
+    fn describe(&self, rules: &Rules) -> String;
+}
+
+/// The columns are the shapes to show, X/Y/Z are rock, paper and scissors by default
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct AsShapes(pub [Shape; 3]);
+
+impl Default for AsShapes {
+    fn default() -> Self {
+        AsShapes([Shape::ROCK, Shape::PAPER, Shape::SCISSORS])
+    }
+}
+
+impl GuideInterpretation for AsShapes {
+    fn round(&self, _rules: &Rules, theire: Shape, column: Column) -> Option<Round> {
+        Some(Round {
+            mine: self.0[column as usize],
+            theire,
+        })
+    }
+
+    fn describe(&self, rules: &Rules) -> String {
+        let [x, y, z] = self.0.map(|shape| rules.name(shape));
+        format!("X = {}, Y = {}, Z = {}", x, y, z)
+    }
+}
+
+/// The columns are the outcomes to reach, X/Y/Z are lose, draw and win by default
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct AsOutcomes(pub [Outcome; 3]);
+
+impl Default for AsOutcomes {
+    fn default() -> Self {
+        AsOutcomes(Outcome::ALL)
+    }
+}
+
+impl GuideInterpretation for AsOutcomes {
+    fn round(&self, rules: &Rules, theire: Shape, column: Column) -> Option<Round> {
+        Round::new_from_indended_outcome(rules, theire, self.0[column as usize])
+    }
+
+    fn describe(&self, _rules: &Rules) -> String {
+        let [x, y, z] = self.0;
+        format!("X = {:?}, Y = {:?}, Z = {:?}", x, y, z)
+    }
+}
+
+/// Total score of the guide, `None` when a round cannot be played
+pub fn guide_score(
+    guide: &[(Shape, Column)],
+    rules: &Rules,
+    interpretation: &dyn GuideInterpretation,
+) -> Option<u64> {
+    guide
+        .iter()
+        .map(|&(theire, column)| Some(interpretation.round(rules, theire, column)?.score(rules)))
+        .sum()
+}
+
+/// Every way to map X/Y/Z to different shapes or to different outcomes
+pub fn every_interpretation(rules: &Rules) -> Vec<Box<dyn GuideInterpretation>> {
+    let mut interpretations: Vec<Box<dyn GuideInterpretation>> = Vec::new();
+    for x in rules.shapes() {
+        for y in rules.shapes().filter(|&y| y != x) {
+            for z in rules.shapes().filter(|&z| z != x && z != y) {
+                interpretations.push(Box::new(AsShapes([x, y, z])));
+            }
+        }
+    }
+    for x in Outcome::ALL {
+        for y in Outcome::ALL.into_iter().filter(|&y| y != x) {
+            for z in Outcome::ALL.into_iter().filter(|&z| z != x && z != y) {
+                interpretations.push(Box::new(AsOutcomes([x, y, z])));
+            }
+        }
+    }
+    interpretations
+}
+
+/// Score of the guide with one interpretation
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Scored {
+    pub score: u64,
+    /// What each column stood for
+    pub mapping: String,
+}
+
+/// Lowest and highest scores of a guide over some interpretations
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ScoreRange {
+    pub worst: Scored,
+    pub best: Scored,
+}
+
+/// Score the guide with each interpretation, `None` when none can play it
+///
+/// ```
+/// use aoc_common::Solution;
+/// use day2::{every_interpretation, score_range, Day2, Rules};
+///
+/// let guide = Day2::parse("A Y\nB X\nC Z").unwrap();
+/// let rules = Rules::default();
+/// let range = score_range(&guide, &rules, every_interpretation(&rules)).unwrap();
+/// assert_eq!((range.worst.score, range.best.score), (6, 24));
+/// assert_eq!(range.best.mapping, "X = Scissors, Y = Paper, Z = Rock");
+/// ```
+pub fn score_range(
+    guide: &[(Shape, Column)],
+    rules: &Rules,
+    interpretations: impl IntoIterator<Item = Box<dyn GuideInterpretation>>,
+) -> Option<ScoreRange> {
+    let mut range: Option<ScoreRange> = None;
+
+    for interpretation in interpretations {
+        let Some(score) = guide_score(guide, rules, interpretation.as_ref()) else {
+            continue;
+        };
+        let scored = Scored {
+            score,
+            mapping: interpretation.describe(rules),
+        };
+
+        match &mut range {
+            None => {
+                range = Some(ScoreRange {
+                    worst: scored.clone(),
+                    best: scored,
+                })
+            }
+            Some(range) if score < range.worst.score => range.worst = scored,
+            Some(range) if score > range.best.score => range.best = scored,
+            Some(_) => (),
+        }
+    }
+
+    range
+}
+
+/// Way a player picks its shapes in a [`Match`]
+pub trait Strategy {
+    /// Shape for the next round, knowing the shapes the opponent showed so far
+    fn next_shape(&mut self, rules: &Rules, opponent: &[Shape]) -> Shape;
+}
+
+/// Shapes played in order, over and over
+#[derive(Clone, Debug)]
+pub struct Sequence {
+    shapes: Vec<Shape>,
+    next: usize,
+}
+
+impl Sequence {
+    pub fn new(shapes: Vec<Shape>) -> Self {
+        Sequence { shapes, next: 0 }
+    }
+
+    /// Their shapes in the guide
+    pub fn theirs(guide: &[(Shape, Column)]) -> Self {
+        Sequence::new(guide.iter().map(|&(theire, _)| theire).collect())
+    }
+
+    /// My shapes in the guide, `None` when a round cannot be played
+    pub fn mine(
+        guide: &[(Shape, Column)],
+        rules: &Rules,
+        interpretation: &dyn GuideInterpretation,
+    ) -> Option<Self> {
+        let shapes = guide
+            .iter()
+            .map(|&(theire, column)| Some(interpretation.round(rules, theire, column)?.mine))
+            .collect::<Option<_>>()?;
+        Some(Sequence::new(shapes))
+    }
+}
+
+impl Strategy for Sequence {
+    fn next_shape(&mut self, _rules: &Rules, _opponent: &[Shape]) -> Shape {
+        let shape = self.shapes.get(self.next).copied().unwrap_or(Shape(0));
+        self.next = (self.next + 1) % self.shapes.len().max(1);
+        shape
+    }
+}
+
+/// Shapes picked at random, the same ones for the same seed
+#[derive(Clone, Debug)]
+pub struct Random {
+    state: u64,
+}
+
+impl Random {
+    pub fn new(seed: u64) -> Self {
+        Random { state: seed }
+    }
+
+    /// SplitMix64
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+}
+
+impl Strategy for Random {
+    fn next_shape(&mut self, rules: &Rules, _opponent: &[Shape]) -> Shape {
+        Shape((self.next_u64() % rules.len() as u64) as usize)
+    }
+}
+
+/// Beats the shape the opponent showed the most so far
+#[derive(Clone, Debug, Default)]
+pub struct FrequencyCounter {
+    counts: Vec<usize>,
+    seen: usize,
+}
+
+impl Strategy for FrequencyCounter {
+    fn next_shape(&mut self, rules: &Rules, opponent: &[Shape]) -> Shape {
+        self.counts.resize(rules.len(), 0);
+        for shape in &opponent[self.seen..] {
+            self.counts[shape.0] += 1;
+        }
+        self.seen = opponent.len();
+
+        // The first shape wins the ties
+        let most = rules
+            .shapes()
+            .max_by_key(|shape| (self.counts[shape.0], Reverse(shape.0)))
+            .unwrap_or(Shape(0));
+        rules.choose(most, Outcome::Win).unwrap_or(Shape(0))
+    }
+}
+
+/// Beats the last shape of the opponent
+#[derive(Clone, Debug, Default)]
+pub struct BeatLast;
+
+impl Strategy for BeatLast {
+    fn next_shape(&mut self, rules: &Rules, opponent: &[Shape]) -> Shape {
+        opponent
+            .last()
+            .and_then(|&last| rules.choose(last, Outcome::Win))
+            .unwrap_or(Shape(0))
+    }
+}
+
+/// Two players facing each other for a number of rounds, one round per step
+///
+/// ```
+/// use day2::{BeatLast, Match, Rules, Sequence, Shape};
+///
+/// let rock_paper = Sequence::new(vec![Shape::ROCK, Shape::PAPER]);
+/// let mut game = Match::new(Rules::default(), Box::new(rock_paper), Box::new(BeatLast), 3);
+/// game.run();
+/// // Draws with rocks then papers, then rock crushes the scissors beating paper
+/// assert_eq!(game.scores, [vec![4, 9, 16], vec![4, 9, 12]]);
+/// ```
+pub struct Match {
+    rules: Rules,
+    players: [Box<dyn Strategy>; 2],
+    /// Shapes shown by each player
+    pub shapes: [Vec<Shape>; 2],
+    /// Total score of each player after each round
+    pub scores: [Vec<u64>; 2],
+    rounds: usize,
+}
+
+impl Match {
+    pub fn new(
+        rules: Rules,
+        first: Box<dyn Strategy>,
+        second: Box<dyn Strategy>,
+        rounds: usize,
+    ) -> Self {
+        Match {
+            rules,
+            players: [first, second],
+            shapes: [Vec::new(), Vec::new()],
+            scores: [Vec::new(), Vec::new()],
+            rounds,
+        }
+    }
+
+    pub fn play_round(&mut self) {
+        let first = self.players[0].next_shape(&self.rules, &self.shapes[1]);
+        let second = self.players[1].next_shape(&self.rules, &self.shapes[0]);
+
+        for (player, (mine, theire)) in [(first, second), (second, first)].into_iter().enumerate() {
+            let score = Round { mine, theire }.score(&self.rules);
+            let total = self.scores[player].last().copied().unwrap_or(0);
+            self.scores[player].push(total + score);
+            self.shapes[player].push(mine);
+        }
+    }
+
+    /// Play the rounds left
+    pub fn run(&mut self) {
+        while self.step() {}
+    }
+
+    /// Total score of each player
+    pub fn totals(&self) -> [u64; 2] {
+        self.scores
+            .each_ref()
+            .map(|scores| scores.last().copied().unwrap_or(0))
+    }
+}
+
+impl Simulation for Match {
+    fn step(&mut self) -> bool {
+        if self.scores[0].len() >= self.rounds {
+            return false;
+        }
+        self.play_round();
+        true
+    }
+}
+
+/// Score curves of the players, `1` and `2`, sampled over the rounds played
+impl Render for Match {
+    fn render(&self) -> Frame {
+        const WIDTH: usize = 60;
+        const HEIGHT: usize = 20;
+        let colors = [[255, 100, 100], [100, 160, 255]];
+
+        let played = self.scores[0].len();
+        let columns = played.clamp(1, WIDTH);
+        let top = self.totals().into_iter().max().unwrap_or(0).max(1);
+        let row = |score: u64| HEIGHT - 1 - (score * (HEIGHT as u64 - 1) / top) as usize;
+
+        let bounds = Bounds {
+            min: Point::ORIGIN,
+            max: Point::new(columns as isize - 1, HEIGHT as isize - 1),
+        };
+        Frame::draw(bounds, |p| {
+            let round = (p.x as usize + 1) * played / columns;
+            let on_curve =
+                |player: usize| round > 0 && row(self.scores[player][round - 1]) == p.y as usize;
+            match (on_curve(0), on_curve(1)) {
+                (true, true) => '*'.into(),
+                (true, false) => Glyph::colored('1', colors[0]),
+                (false, true) => Glyph::colored('2', colors[1]),
+                (false, false) => Glyph::BLANK,
+            }
+        })
+        .with_caption(format!(
+            "round {}, {} - {}",
+            played,
+            self.totals()[0],
+            self.totals()[1]
+        ))
+    }
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+
+    /// Their shape and the second column of the strategy guide
+    type Input = Vec<(Shape, Column)>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: include_str!("../example"),
+        part1: Some("15"),
+        part2: Some("12"),
+    }];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input, |line| {
+            let mut hands = line.split(' ');
+            let theire = next_token(line, &mut hands, "their shape")?;
+            let second = next_token(line, &mut hands, "the second column")?;
+            Ok((
+                theire
+                    .parse()
+                    .map_err(|e: ParseError| e.within(line, theire))?,
+                second
+                    .parse()
+                    .map_err(|e: ParseError| e.within(line, second))?,
+            ))
+        })
+    }
+
+    // Part 1: entry are interpreted as shapes
+    fn part1(guide: &Self::Input) -> Result<Self::Answer1> {
+        guide_score(guide, &Rules::default(), &AsShapes::default())
+            .ok_or_else(|| anyhow!("A round of the guide cannot be played"))
+    }
+
+    // Part 2: Entries are interpreted as their play and the intended result
+    fn part2(guide: &Self::Input) -> Result<Self::Answer2> {
+        guide_score(guide, &Rules::default(), &AsOutcomes::default())
+            .ok_or_else(|| anyhow!("A round of the guide cannot be played"))
+    }
+
+    /// Me playing the guide against them
+    fn simulation(guide: &Self::Input, part: Part) -> Option<Box<dyn Simulation>> {
+        let rules = Rules::default();
+        let mine = match part {
+            Part::One => Sequence::mine(guide, &rules, &AsShapes::default()),
+            Part::Two => Sequence::mine(guide, &rules, &AsOutcomes::default()),
+        }?;
+        let theirs = Sequence::theirs(guide);
+        Some(Box::new(Match::new(
+            rules,
+            Box::new(mine),
+            Box::new(theirs),
+            guide.len(),
+        )))
+    }
+}
@@ -1,110 +1,45 @@
 // https://adventofcode.com/2022/day/2
 
-use std::{
-    fs::File,
-    io::{BufRead, BufReader, Read},
-};
-
-#[derive(Copy, Clone, Debug)]
-enum Shape {
-    Rock = 1,
-    Paper = 2,
-    Sissors = 3,
-}
-
-impl From<&str> for Shape {
-    fn from(raw: &str) -> Self {
-        match raw {
-            "A" | "X" => Shape::Rock,
-            "B" | "Y" => Shape::Paper,
-            "C" | "Z" => Shape::Sissors,
-            _ => panic!("Wrong input format"),
-        }
-    }
-}
-
-#[derive(Copy, Clone, Debug, PartialEq)]
-enum Outcome {
-    Loss = 0,
-    Draw = 3,
-    Win = 6,
-}
+use std::fs;
 
-impl From<&str> for Outcome {
-    fn from(raw: &str) -> Self {
-        match raw {
-            "X" => Outcome::Loss,
-            "Y" => Outcome::Draw,
-            "Z" => Outcome::Win,
-            _ => panic!("Wrong input format"),
-        }
-    }
-}
-
-#[derive(Copy, Clone, Debug)]
-struct Round {
-    mine: Shape,
-    theire: Shape,
-}
+use aoc_common::Solution;
+use day2::{
+    every_interpretation, score_range, AsShapes, BeatLast, Day2, FrequencyCounter, Match, Random,
+    Rules, Sequence, Strategy,
+};
 
-impl Round {
-    fn play(&self) -> Outcome {
-        match (self.mine, self.theire) {
-            (Shape::Rock, Shape::Paper) => Outcome::Loss,
-            (Shape::Paper, Shape::Rock) => Outcome::Win,
-            (Shape::Paper, Shape::Sissors) => Outcome::Loss,
-            (Shape::Sissors, Shape::Paper) => Outcome::Win,
-            (Shape::Sissors, Shape::Rock) => Outcome::Loss,
-            (Shape::Rock, Shape::Sissors) => Outcome::Win,
-            _ => Outcome::Draw,
-        }
+fn main() -> anyhow::Result<()> {
+    let guide = Day2::parse(&fs::read_to_string("input")?)?;
+
+    println!("Part 1: My final score is: {}", Day2::part1(&guide)?);
+    println!("Part 2: My final score is: {}", Day2::part2(&guide)?);
+
+    let rules = Rules::default();
+    if let Some(range) = score_range(&guide, &rules, every_interpretation(&rules)) {
+        println!(
+            "Worst score is {} with {}",
+            range.worst.score, range.worst.mapping
+        );
+        println!(
+            "Best score is {} with {}",
+            range.best.score, range.best.mapping
+        );
     }
 
-    fn new_from_indended_outcome(theire: Shape, outcome: Outcome) -> Self {
-        for mine in [Shape::Rock, Shape::Paper, Shape::Sissors] {
-            let round = Round { mine, theire };
-            if round.play() == outcome {
-                return round;
-            }
+    // The shapes of the guide against other strategies
+    let opponents: [(&str, Box<dyn Strategy>); 3] = [
+        ("random", Box::new(Random::new(2022))),
+        ("frequency counter", Box::new(FrequencyCounter::default())),
+        ("beat last", Box::new(BeatLast)),
+    ];
+    for (name, opponent) in opponents {
+        if let Some(mine) = Sequence::mine(&guide, &rules, &AsShapes::default()) {
+            let mut game = Match::new(rules.clone(), Box::new(mine), opponent, guide.len());
+            game.run();
+            let [mine, theirs] = game.totals();
+            println!("Guide against {}: {} - {}", name, mine, theirs);
         }
-
-        unreachable!();
     }
-}
-
-fn main() {
-    // Part 1: entry are interpreted as shapes
-    let input = BufReader::new(File::open("input").unwrap())
-        .lines()
-        .map(|line| {
-            let line = line.unwrap();
-            let mut hands = line.split(' ');
-            Round {
-                theire: hands.next().unwrap().into(),
-                mine: hands.next().unwrap().into(),
-            }
-        });
-
-    let score = input
-        .map(|round| round.mine as u64 + round.play() as u64)
-        .fold(0, |score, round| score + round);
-
-    println!("Part 1: My final score is: {}", score);
-
-    // Part 2: Entries are interpreted as their play and the intended result
-    let input = BufReader::new(File::open("input").unwrap())
-        .lines()
-        .map(|line| {
-            let line = line.unwrap();
-            let mut hands = line.split(' ');
-            let theire = hands.next().unwrap().into();
-            let outcome = hands.next().unwrap().into();
-            Round::new_from_indended_outcome(theire, outcome)
-        });
-
-    let score = input
-        .map(|round| round.mine as u64 + round.play() as u64)
-        .fold(0, |score, round| score + round);
 
-    println!("Part 2: My final score is: {}", score);
+    Ok(())
 }
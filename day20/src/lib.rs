@@ -0,0 +1,141 @@
+// https://adventofcode.com/2022/day/20
+
+use aoc_common::{parse_lines, parse_token, Example, ParseError, Result, Solution};
+
+pub struct Message {
+    array: Vec<(usize, isize)>,
+}
+
+impl Message {
+    pub fn new(input: &[isize]) -> Message {
+        Message {
+            array: input.iter().cloned().enumerate().collect(),
+        }
+    }
+
+    fn move_left(&mut self, id: usize) {
+        let position = self.array.iter().position(|(n, _)| *n == id).unwrap();
+
+        let value = self.array.remove(position);
+        if position == 0 {
+            self.array.insert(self.array.len() - 1, value);
+        } else {
+            self.array.insert(position - 1, value);
+        }
+    }
+
+    fn move_right(&mut self, id: usize) {
+        let position = self.array.iter().position(|(n, _)| *n == id).unwrap();
+
+        let value = self.array.remove(position);
+        if position >= self.array.len() {
+            self.array.insert(1, value);
+        } else {
+            self.array.insert(position + 1, value);
+        }
+    }
+
+    pub fn shuffle(&mut self) {
+        for i in 0..self.array.len() {
+            let pos = self.array.iter().position(|(n, _)| *n == i).unwrap();
+
+            // println!("{:?}", &self.array);
+            let value = self.array[pos].1;
+            if value > 0 {
+                for _ in 0..(value % (self.array.len() - 1) as isize) {
+                    self.move_right(i);
+                }
+            } else {
+                for _ in 0..((-value) % (self.array.len() - 1) as isize) {
+                    self.move_left(i);
+                    // println!("{:?}", &self.array);
+                }
+            }
+        }
+
+        // println!("{:?}", &self.array);
+        // println!();
+    }
+
+    /// Sum of the 1000th, 2000th and 3000th values after 0
+    pub fn grove_coordinates(&self) -> isize {
+        let coordinate_1 = self.iter().skip_while(|v| *v != 0).nth(1000).unwrap();
+        let coordinate_2 = self.iter().skip_while(|v| *v != 0).nth(2000).unwrap();
+        let coordinate_3 = self.iter().skip_while(|v| *v != 0).nth(3000).unwrap();
+
+        coordinate_1 + coordinate_2 + coordinate_3
+    }
+
+    /// Returns an iterator that infinitly loop arround the values
+    /// Makes it possible to easily get the "1000th value after 0"
+    pub fn iter(&'_ self) -> Iter<'_> {
+        Iter {
+            message: self,
+            id: 0,
+        }
+    }
+}
+
+pub struct Iter<'a> {
+    message: &'a Message,
+    id: usize,
+}
+
+impl<'a> Iterator for Iter<'a> {
+    type Item = isize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let pos = self.id;
+        self.id = (self.id + 1) % self.message.array.len();
+        Some(self.message.array[pos].1)
+    }
+}
+
+pub const DECRYPTION_KEY: isize = 811589153;
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+
+    type Input = Vec<isize>;
+    type Answer1 = isize;
+    type Answer2 = isize;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: include_str!("../example"),
+        part1: Some("3"),
+        part2: Some("1623178306"),
+    }];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let numbers: Self::Input = parse_lines(input, |line| parse_token(line, line))?;
+
+        // The grove coordinates are counted from the 0
+        if !numbers.contains(&0) {
+            return Err(ParseError::missing(input, "expected a 0"));
+        }
+
+        Ok(numbers)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1> {
+        let mut message = Message::new(input);
+
+        message.shuffle();
+
+        Ok(message.grove_coordinates())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2> {
+        let input: Vec<isize> = input.iter().map(|n| n * DECRYPTION_KEY).collect();
+
+        let mut message = Message::new(&input);
+
+        for _ in 0..10 {
+            message.shuffle();
+        }
+
+        Ok(message.grove_coordinates())
+    }
+}
@@ -0,0 +1,149 @@
+// https://adventofcode.com/2022/day/21
+
+use std::{collections::HashMap, str::FromStr};
+
+use anyhow::{Error, Result};
+use aoc_common::{next_token, parse_lines, Example, ParseError, Solution, Unsolved};
+
+#[derive(Debug, Clone)]
+pub enum Element {
+    Add(String, String),
+    Sub(String, String),
+    Mul(String, String),
+    Div(String, String),
+    Number(i64),
+}
+
+impl FromStr for Element {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut elements = s.split(' ');
+
+        let first = next_token(s, &mut elements, "a number or a monkey")?.to_owned();
+
+        if let Ok(num) = first.parse::<i64>() {
+            Ok(Element::Number(num))
+        } else {
+            let operation = next_token(s, &mut elements, "an operation")?;
+            let second = next_token(s, &mut elements, "a monkey")?.to_owned();
+
+            match operation {
+                "+" => Ok(Element::Add(first, second)),
+                "-" => Ok(Element::Sub(first, second)),
+                "*" => Ok(Element::Mul(first, second)),
+                "/" => Ok(Element::Div(first, second)),
+                _ => Err(ParseError::new(s, operation, "expected +, -, * or /")),
+            }
+        }
+    }
+}
+
+impl Element {
+    /// Result of the operation, an error on a division by 0 or an overflow
+    pub fn calculate(&self, first: i64, second: i64) -> anyhow::Result<i64> {
+        let (result, symbol) = match self {
+            Element::Add(_, _) => (first.checked_add(second), '+'),
+            Element::Sub(_, _) => (first.checked_sub(second), '-'),
+            Element::Mul(_, _) => (first.checked_mul(second), '*'),
+            Element::Div(_, _) => (first.checked_div(second), '/'),
+            Element::Number(_) => return Err(Error::msg("Not an operation.")),
+        };
+        result.ok_or_else(|| Error::msg(format!("Cannot compute {} {} {}", first, symbol, second)))
+    }
+
+    pub fn is_operation(&self) -> bool {
+        !matches!(self, Self::Number(_))
+    }
+
+    pub fn get_orerands(&self) -> Result<(String, String)> {
+        match self {
+            Element::Add(f, s) => Ok((f.to_owned(), s.to_owned())),
+            Element::Sub(f, s) => Ok((f.to_owned(), s.to_owned())),
+            Element::Mul(f, s) => Ok((f.to_owned(), s.to_owned())),
+            Element::Div(f, s) => Ok((f.to_owned(), s.to_owned())),
+            Element::Number(_) => Err(Error::msg("Not an operation.")),
+        }
+    }
+
+    pub fn get_number(&self) -> Result<i64> {
+        match self {
+            Element::Number(n) => Ok(*n),
+            _ => Err(Error::msg("Not an number.")),
+        }
+    }
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+
+    /// Monkeys' jobs by name
+    type Input = HashMap<String, Element>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: include_str!("../example"),
+        part1: Some("152"),
+        part2: None,
+    }];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let jobs = parse_lines(input, |line| {
+            let mut elements = line.split(": ");
+            let name = next_token(line, &mut elements, "a monkey")?;
+            let job = next_token(line, &mut elements, "a job")?;
+            Ok((
+                name.to_owned(),
+                job.parse::<Element>().map_err(|e| e.within(line, job))?,
+            ))
+        })?;
+
+        let monkeys: HashMap<String, Element> = jobs.iter().cloned().collect();
+
+        // Every monkey has to wait for monkeys that exist
+        for (line, (_, job)) in input.lines().zip(jobs.iter()) {
+            if let Ok((first, second)) = job.get_orerands() {
+                for operand in [first, second] {
+                    if !monkeys.contains_key(&operand) {
+                        let (i, _) = line.rmatch_indices(&operand).next().unwrap_or((0, ""));
+                        let token = &line[i..i + operand.len()];
+                        return Err(ParseError::new(input, token, "no monkey with this name"));
+                    }
+                }
+            }
+        }
+        if !monkeys.contains_key("root") {
+            return Err(ParseError::missing(input, "expected the monkey root"));
+        }
+
+        Ok(monkeys)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1> {
+        let mut input = input.clone();
+
+        let keys: Vec<String> = input.keys().cloned().collect();
+
+        while input["root"].is_operation() {
+            for name in keys.iter() {
+                if input[name].is_operation() {
+                    let (first, second) = input[name].get_orerands()?;
+                    if !input[&first].is_operation() && !input[&second].is_operation() {
+                        let value = input[name]
+                            .calculate(input[&first].get_number()?, input[&second].get_number()?)?;
+                        input.insert(name.to_owned(), Element::Number(value));
+                    }
+                }
+            }
+        }
+
+        input["root"].get_number()
+    }
+
+    fn part2(_input: &Self::Input) -> Result<Self::Answer2> {
+        Err(Unsolved.into())
+    }
+}
@@ -0,0 +1,313 @@
+// https://adventofcode.com/2022/day/22
+
+use std::str::FromStr;
+
+use anyhow::Result;
+use aoc_common::{
+    parse_token, Example, Frame, Glyph, Grid, ParseError, Part, Point, Render, Simulation,
+    Solution, SparseGrid, Unsolved,
+};
+
+#[derive(Debug, Clone, Copy, Default)]
+pub enum Direction {
+    Up = 3,
+    Left = 2,
+    Down = 1,
+    #[default]
+    Right = 0,
+}
+
+impl Direction {
+    pub fn turn(&mut self, turn: Turn) {
+        match turn {
+            Turn::R => match self {
+                Direction::Up => *self = Direction::Right,
+                Direction::Left => *self = Direction::Up,
+                Direction::Down => *self = Direction::Left,
+                Direction::Right => *self = Direction::Down,
+            },
+            Turn::L => match self {
+                Direction::Up => *self = Direction::Left,
+                Direction::Left => *self = Direction::Down,
+                Direction::Down => *self = Direction::Right,
+                Direction::Right => *self = Direction::Up,
+            },
+        }
+    }
+
+    pub fn arrow(self) -> char {
+        match self {
+            Direction::Up => '^',
+            Direction::Left => '<',
+            Direction::Down => 'v',
+            Direction::Right => '>',
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Turn {
+    R,
+    L,
+}
+
+impl From<Direction> for Point {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::Up => Point::UP,
+            Direction::Left => Point::LEFT,
+            Direction::Down => Point::DOWN,
+            Direction::Right => Point::RIGHT,
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct Cursor {
+    pub position: Point,
+    pub direction: Direction,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Tile {
+    Open,
+    Wall,
+}
+
+impl TryFrom<char> for Tile {
+    type Error = char;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '.' => Ok(Tile::Open),
+            '#' => Ok(Tile::Wall),
+            _ => Err(value),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Board {
+    map: SparseGrid<Tile>,
+    visited: SparseGrid<Direction>,
+    pub cursor: Cursor,
+}
+
+impl FromStr for Board {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // Spaces are outside of the board
+        let map = SparseGrid::parse(s, ' ', "`.`, `#` or a space", |tile| {
+            Tile::try_from(tile).ok()
+        })?;
+
+        let start_x = map
+            .points()
+            .filter(|p| p.y == 0)
+            .map(|p| p.x)
+            .min()
+            .ok_or_else(|| ParseError::missing(s, "expected a tile on the first row"))?;
+        let cursor = Cursor {
+            position: Point::new(start_x, 0),
+            ..Default::default()
+        };
+
+        Ok(Board {
+            map,
+            visited: SparseGrid::new(),
+            cursor,
+        })
+    }
+}
+
+impl Board {
+    pub fn move_curor(&mut self, step: Path) -> Result<()> {
+        match step {
+            Path::Turn(turn) => self.cursor.direction.turn(turn),
+            Path::Forward(distance) => {
+                for _ in 0..distance {
+                    if let Tile::Open = self.get_next_tile() {
+                        self.advance_position();
+                    }
+                    self.visited
+                        .insert(self.cursor.position, self.cursor.direction);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn next_tile_position(&self) -> Point {
+        let step = Point::from(self.cursor.direction);
+        let new_pos = self.cursor.position + step;
+        if self.map.contains(new_pos) {
+            return new_pos;
+        }
+
+        // Wrap around to the other end of the row or column
+        let mut wrapped = self.cursor.position;
+        while self.map.contains(wrapped - step) {
+            wrapped -= step;
+        }
+        wrapped
+    }
+
+    fn get_next_tile(&self) -> Tile {
+        self.map[self.next_tile_position()]
+    }
+
+    fn advance_position(&mut self) {
+        self.cursor.position = self.next_tile_position();
+    }
+
+    pub fn password(&self) -> isize {
+        1000 * (self.cursor.position.y + 1)
+            + 4 * (self.cursor.position.x + 1)
+            + (self.cursor.direction as isize)
+    }
+}
+
+/// The visited tiles show the direction they were left in
+impl Render for Board {
+    fn render(&self) -> Frame {
+        let Some(bounds) = self.map.bounds() else {
+            return Frame::from_text("");
+        };
+        Frame::draw(bounds, |p| {
+            if p == self.cursor.position {
+                return Glyph::colored(self.cursor.direction.arrow(), [255, 255, 0]);
+            }
+            match (self.visited.get(p), self.map.get(p)) {
+                (Some(direction), _) => Glyph::colored(direction.arrow(), [0, 200, 0]),
+                (None, Some(Tile::Open)) => '.'.into(),
+                (None, Some(Tile::Wall)) => '#'.into(),
+                (None, None) => Glyph::BLANK,
+            }
+        })
+        .with_caption(format!("password {}", self.password()))
+    }
+}
+
+/// The board walked along the path, one instruction per step
+pub struct Walk {
+    pub board: Board,
+    path: Vec<Path>,
+    next: usize,
+}
+
+impl Walk {
+    pub fn new(board: Board, path: Vec<Path>) -> Self {
+        Walk {
+            board,
+            path,
+            next: 0,
+        }
+    }
+}
+
+impl Simulation for Walk {
+    fn step(&mut self) -> bool {
+        let Some(&step) = self.path.get(self.next) else {
+            return false;
+        };
+        self.next += 1;
+        self.board.move_curor(step).is_ok()
+    }
+}
+
+impl Render for Walk {
+    fn render(&self) -> Frame {
+        let frame = self.board.render();
+        let caption = format!(
+            "instruction {} of {}, {}",
+            self.next,
+            self.path.len(),
+            frame.caption
+        );
+        frame.with_caption(caption)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Path {
+    Turn(Turn),
+    Forward(usize),
+}
+
+pub fn parse_path(s: &str) -> Result<Vec<Path>, ParseError> {
+    let mut path = Vec::new();
+    let mut num_start = 0;
+
+    for (i, c) in s.char_indices() {
+        let turn = match c {
+            'R' => Turn::R,
+            'L' => Turn::L,
+            _ => continue,
+        };
+
+        if num_start < i {
+            path.push(Path::Forward(parse_token(s, &s[num_start..i])?));
+        }
+        path.push(Path::Turn(turn));
+        num_start = i + 1;
+    }
+
+    if num_start < s.len() {
+        path.push(Path::Forward(parse_token(s, &s[num_start..])?));
+    }
+
+    Ok(path)
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    const DAY: u8 = 22;
+
+    type Input = (Board, Vec<Path>);
+    type Answer1 = isize;
+    type Answer2 = isize;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: include_str!("../example"),
+        part1: Some("6032"),
+        part2: None,
+    }];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let (board, path) = input
+            .split_once("\n\n")
+            .ok_or_else(|| ParseError::missing(input, "expected the path after an empty line"))?;
+        let path = path.trim_end();
+
+        Ok((
+            board
+                .parse()
+                .map_err(|e: ParseError| e.within(input, board))?,
+            parse_path(path).map_err(|e| e.within(input, path))?,
+        ))
+    }
+
+    fn part1((board, path): &Self::Input) -> Result<Self::Answer1> {
+        let mut board = board.clone();
+
+        for step in path {
+            board.move_curor(*step)?;
+        }
+
+        Ok(board.password())
+    }
+
+    fn part2(_input: &Self::Input) -> Result<Self::Answer2> {
+        Err(Unsolved.into())
+    }
+
+    fn simulation((board, path): &Self::Input, part: Part) -> Option<Box<dyn Simulation>> {
+        match part {
+            Part::One => Some(Box::new(Walk::new(board.clone(), path.clone()))),
+            Part::Two => None,
+        }
+    }
+}
@@ -0,0 +1,217 @@
+// https://adventofcode.com/2022/day/23
+
+use std::{
+    collections::{HashMap, VecDeque},
+    str::FromStr,
+};
+
+use anyhow::Result;
+use aoc_common::{
+    Example, Frame, Glyph, Grid, ParseError, Part, Point, Render, Simulation, Solution, SparseGrid,
+};
+
+#[derive(Clone)]
+pub enum Tile {
+    Elf,
+}
+
+#[derive(Clone)]
+pub struct Map {
+    map: SparseGrid<Tile>,
+    checks: VecDeque<Vec<Point>>,
+}
+
+impl Map {
+    // Returns Elf's next move
+    fn next_move(&self, elf: Point) -> Point {
+        // Check if there is any neighbor
+        let has_neighbor = elf.neighbours8().any(|to_test| self.map.contains(to_test));
+
+        if !has_neighbor {
+            // println!("Elf {:?} is alone, not moving!", elf);
+            return elf;
+        }
+
+        for checks in &self.checks {
+            let can_move = !checks.iter().any(|offset| {
+                let to_check = elf + *offset;
+                self.map.contains(to_check)
+            });
+
+            if can_move {
+                return elf + checks[0];
+            }
+        }
+
+        elf
+    }
+
+    pub fn move_elves(&mut self) -> bool {
+        let mut new_map = SparseGrid::new();
+        let mut any_moved = false;
+        let mut collisions: HashMap<Point, usize> = HashMap::new();
+
+        // Pre-calculate collisions:
+        for pos in self.map.points() {
+            let new_pos = self.next_move(pos);
+
+            let n: usize = collisions.get(&new_pos).cloned().unwrap_or_default();
+            collisions.insert(new_pos, n + 1);
+        }
+
+        for pos in self.map.points() {
+            let new_pos = self.next_move(pos);
+
+            let should_move = collisions[&new_pos] == 1;
+
+            // let should_move = true;
+            any_moved |= new_pos != pos;
+
+            // dbg!(should_move);
+            if should_move {
+                // println!("Moving {:?} to {:?}", pos, new_pos);
+                new_map.insert(new_pos, Tile::Elf);
+            } else {
+                // println!("Not moving {:?}", pos);
+                new_map.insert(pos, Tile::Elf);
+            }
+        }
+
+        self.map = new_map;
+
+        // Rotate the checks
+        let checks = self.checks.pop_front().unwrap();
+        self.checks.push_back(checks);
+
+        any_moved
+    }
+
+    pub fn count_space(&self) -> usize {
+        let bounds = self.map.bounds().unwrap();
+        (bounds.width() * bounds.height()) as usize - self.map.len()
+    }
+}
+
+impl Render for Map {
+    fn render(&self) -> Frame {
+        let frame = match self.map.bounds() {
+            Some(bounds) => Frame::draw(bounds, |p| {
+                if self.map.contains(p) {
+                    Glyph::colored('#', [0, 200, 0])
+                } else {
+                    '.'.into()
+                }
+            }),
+            None => Frame::from_text(""),
+        };
+        frame.with_caption(format!("{} empty tiles", self.count_space()))
+    }
+}
+
+/// Rounds of the elves spreading out, until `rounds` or until no one moves
+pub struct Spread {
+    pub map: Map,
+    pub round: usize,
+    rounds: Option<usize>,
+    over: bool,
+}
+
+impl Spread {
+    pub fn new(map: Map, rounds: Option<usize>) -> Self {
+        Spread {
+            map,
+            round: 0,
+            rounds,
+            over: false,
+        }
+    }
+}
+
+impl Simulation for Spread {
+    fn step(&mut self) -> bool {
+        if self.over || Some(self.round) == self.rounds {
+            return false;
+        }
+        self.over = !self.map.move_elves();
+        self.round += 1;
+        true
+    }
+}
+
+impl Render for Spread {
+    fn render(&self) -> Frame {
+        let frame = self.map.render();
+        let caption = format!("round {}, {}", self.round, frame.caption);
+        frame.with_caption(caption)
+    }
+}
+
+impl FromStr for Map {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let map = SparseGrid::parse(s, '.', "`.` or `#`", |c| (c == '#').then_some(Tile::Elf))?;
+        if map.is_empty() {
+            return Err(ParseError::missing(s, "expected at least one elf"));
+        }
+
+        // Create list of checks
+        let checks = VecDeque::from_iter([
+            vec![Point::new(0, -1), Point::new(1, -1), Point::new(-1, -1)],
+            vec![Point::new(0, 1), Point::new(1, 1), Point::new(-1, 1)],
+            vec![Point::new(-1, 0), Point::new(-1, 1), Point::new(-1, -1)],
+            vec![Point::new(1, 0), Point::new(1, 1), Point::new(1, -1)],
+        ]);
+
+        Ok(Map { map, checks })
+    }
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u8 = 23;
+
+    type Input = Map;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: include_str!("../example"),
+        part1: Some("110"),
+        part2: Some("20"),
+    }];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.parse()
+    }
+
+    fn part1(map: &Self::Input) -> Result<Self::Answer1> {
+        let mut map = map.clone();
+
+        for _ in 0..10 {
+            map.move_elves();
+        }
+
+        Ok(map.count_space())
+    }
+
+    fn part2(map: &Self::Input) -> Result<Self::Answer2> {
+        let mut map = map.clone();
+
+        let mut round = 1;
+        while map.move_elves() {
+            round += 1;
+        }
+
+        Ok(round)
+    }
+
+    fn simulation(map: &Self::Input, part: Part) -> Option<Box<dyn Simulation>> {
+        let rounds = match part {
+            Part::One => Some(10),
+            Part::Two => None,
+        };
+        Some(Box::new(Spread::new(map.clone(), rounds)))
+    }
+}
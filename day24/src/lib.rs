@@ -0,0 +1,293 @@
+// https://adventofcode.com/2022/day/24
+
+use std::{
+    collections::{HashMap, HashSet},
+    iter,
+    str::FromStr,
+};
+
+use anyhow::{anyhow, Result};
+use aoc_common::{
+    bfs, char_at, next_token, Bounds, Example, Frame, Glyph, ParseError, Part, Point, Render,
+    Simulation, Solution,
+};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl From<Direction> for Point {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::Up => Point::UP,
+            Direction::Right => Point::RIGHT,
+            Direction::Down => Point::DOWN,
+            Direction::Left => Point::LEFT,
+        }
+    }
+}
+
+impl Direction {
+    pub fn arrow(self) -> char {
+        match self {
+            Direction::Up => '^',
+            Direction::Right => '>',
+            Direction::Down => 'v',
+            Direction::Left => '<',
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Maze {
+    wind: Vec<(Point, Direction)>,
+    width: isize,
+    height: isize,
+}
+
+impl FromStr for Maze {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = s.lines();
+
+        // First line is a header, allows us to get the width
+        let header = next_token(s, &mut lines, "the top wall")?;
+        let width = header
+            .len()
+            .checked_sub(2)
+            .ok_or_else(|| ParseError::new(s, header, "expected the top wall"))?;
+
+        // Then comes the wind
+        let mut wind = Vec::new();
+        let mut height = 0;
+        for (y, line) in lines.enumerate() {
+            for (x, (i, c)) in line.char_indices().skip(1).enumerate() {
+                let position = Point::new(x as isize, y as isize);
+                match c {
+                    '^' => wind.push((position, Direction::Up)),
+                    '>' => wind.push((position, Direction::Right)),
+                    'v' => wind.push((position, Direction::Down)),
+                    '<' => wind.push((position, Direction::Left)),
+                    '.' | '#' => (),
+                    _ => {
+                        let token = char_at(line, i);
+                        return Err(ParseError::new(s, token, "expected `.`, `#` or a wind"));
+                    }
+                }
+            }
+            height = y;
+        }
+
+        Ok(Maze {
+            wind,
+            width: width as isize,
+            height: height as isize,
+        })
+    }
+}
+
+impl Maze {
+    pub fn move_wind(&mut self) {
+        for (position, direction) in self.wind.iter_mut() {
+            // The wind wraps around the valley
+            let moved = *position + Point::from(*direction);
+            *position = Point::new(
+                moved.x.rem_euclid(self.width),
+                moved.y.rem_euclid(self.height),
+            );
+        }
+    }
+
+    /// Minutes to cross the valley, the wind keeps blowing during the trip
+    pub fn solve_min_distance(&mut self, back: bool) -> Option<usize> {
+        Some(self.travel(back)?.len() - 1)
+    }
+
+    /// Cross the valley, returns the positions of the expedition minute by minute
+    pub fn travel(&mut self, back: bool) -> Option<Vec<Point>> {
+        let exit = Point::new(self.width - 1, self.height);
+        let (start, goal) = if !back {
+            (Point::new(0, 0), exit)
+        } else {
+            (exit, Point::new(0, 0))
+        };
+
+        let route = self.find_route(start, goal)?;
+        for _ in 1..route.len() {
+            self.move_wind();
+        }
+
+        Some(route)
+    }
+
+    /// Positions from `start` to `goal`, one per minute, dodging the wind
+    pub fn find_route(&self, start: Point, goal: Point) -> Option<Vec<Point>> {
+        let exit = Point::new(self.width - 1, self.height);
+
+        // Where the wind is after each minute, computed as the search goes
+        let mut maze = self.clone();
+        let mut winds: Vec<HashSet<Point>> = Vec::new();
+
+        let moves = |&(position, minute): &(Point, usize)| {
+            while winds.len() <= minute {
+                maze.move_wind();
+                winds.push(maze.wind.iter().map(|(position, _)| *position).collect());
+            }
+            let wind_position = &winds[minute];
+
+            iter::once(position)
+                .chain(position.neighbours())
+                .filter(|to_try| {
+                    (!wind_position.contains(to_try)
+                        && to_try.x >= 0
+                        && to_try.y < self.width
+                        && to_try.y >= 0
+                        && to_try.y < self.height)
+                        || *to_try == exit
+                })
+                .map(|to_try| (to_try, minute + 1))
+                .collect::<Vec<_>>()
+        };
+
+        let paths = bfs((start, 0), moves, |&(position, _)| position == goal);
+        let route = paths.path(paths.goal.as_ref()?)?;
+        Some(route.into_iter().map(|(position, _)| position).collect())
+    }
+
+    /// The valley with its walls, with the expedition at `expedition`
+    fn draw(&self, expedition: Option<Point>) -> Frame {
+        let mut winds: HashMap<Point, (usize, Direction)> = HashMap::new();
+        for &(position, direction) in &self.wind {
+            winds.entry(position).or_insert((0, direction)).0 += 1;
+        }
+
+        let bounds = Bounds {
+            min: Point::new(-1, -1),
+            max: Point::new(self.width, self.height),
+        };
+        let entrance = Point::new(0, -1);
+        let exit = Point::new(self.width - 1, self.height);
+        Frame::draw(bounds, |p| {
+            if Some(p) == expedition {
+                return Glyph::colored('E', [255, 255, 0]);
+            }
+            let inside = (0..self.width).contains(&p.x) && (0..self.height).contains(&p.y);
+            if !inside && p != entrance && p != exit {
+                return '#'.into();
+            }
+            let color = [120, 180, 255];
+            match winds.get(&p) {
+                None => '.'.into(),
+                Some((1, direction)) => Glyph::colored(direction.arrow(), color),
+                Some((n, _)) => {
+                    Glyph::colored(char::from_digit(*n as u32, 10).unwrap_or('*'), color)
+                }
+            }
+        })
+    }
+}
+
+impl Render for Maze {
+    fn render(&self) -> Frame {
+        self.draw(None)
+    }
+}
+
+/// The expedition crossing the valley, one minute per step
+pub struct Expedition {
+    pub maze: Maze,
+    route: Vec<Point>,
+    pub minute: usize,
+}
+
+impl Expedition {
+    /// Plan the trips, going back when `true`, `None` when the wind blocks the way
+    pub fn new(maze: &Maze, trips: &[bool]) -> Option<Self> {
+        let mut planning = maze.clone();
+        let mut route = Vec::new();
+        for &back in trips {
+            // Each trip starts where the previous one ended
+            let skip = usize::from(!route.is_empty());
+            route.extend(planning.travel(back)?.into_iter().skip(skip));
+        }
+
+        Some(Expedition {
+            maze: maze.clone(),
+            route,
+            minute: 0,
+        })
+    }
+}
+
+impl Simulation for Expedition {
+    fn step(&mut self) -> bool {
+        if self.minute + 1 >= self.route.len() {
+            return false;
+        }
+        self.maze.move_wind();
+        self.minute += 1;
+        true
+    }
+}
+
+impl Render for Expedition {
+    fn render(&self) -> Frame {
+        self.maze
+            .draw(Some(self.route[self.minute]))
+            .with_caption(format!("minute {}", self.minute))
+    }
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const DAY: u8 = 24;
+
+    type Input = Maze;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: include_str!("../example"),
+        part1: Some("18"),
+        part2: Some("54"),
+    }];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.parse()
+    }
+
+    fn part1(maze: &Self::Input) -> Result<Self::Answer1> {
+        let mut maze = maze.clone();
+
+        maze.solve_min_distance(false)
+            .ok_or_else(|| anyhow!("The wind blocks the way to the goal"))
+    }
+
+    fn part2(maze: &Self::Input) -> Result<Self::Answer2> {
+        let mut maze = maze.clone();
+
+        let mut trip = |back| {
+            maze.solve_min_distance(back)
+                .ok_or_else(|| anyhow!("The wind blocks the way"))
+        };
+        let goal = trip(false)?;
+        let back = trip(true)?;
+        let again = trip(false)?;
+
+        Ok(goal + back + again)
+    }
+
+    fn simulation(maze: &Self::Input, part: Part) -> Option<Box<dyn Simulation>> {
+        let trips: &[bool] = match part {
+            Part::One => &[false],
+            Part::Two => &[false, true, false],
+        };
+        Some(Box::new(Expedition::new(maze, trips)?))
+    }
+}
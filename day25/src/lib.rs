@@ -0,0 +1,87 @@
+// https://adventofcode.com/2022/day/25
+
+use anyhow::Result;
+use aoc_common::{char_at, parse_lines, Example, ParseError, Solution, Unsolved};
+
+pub fn parse_snafu(s: &str) -> Result<i64, ParseError> {
+    let mut num = 0;
+
+    for (i, c) in s.char_indices() {
+        let n = match c {
+            '=' => -2,
+            '-' => -1,
+            '0' => 0,
+            '1' => 1,
+            '2' => 2,
+            _ => {
+                return Err(ParseError::new(
+                    s,
+                    char_at(s, i),
+                    "expected =, -, 0, 1 or 2",
+                ))
+            }
+        };
+
+        num = num * 5 + n;
+    }
+
+    Ok(num)
+}
+
+pub fn to_snafu(mut num: i64) -> String {
+    let mut res = String::new();
+
+    while num > 0 {
+        let mut rem = num % 5;
+        num /= 5;
+
+        if rem == 3 {
+            rem = -2;
+            num += 1;
+        }
+        if rem == 4 {
+            rem = -1;
+            num += 1;
+        }
+
+        res.push(match rem {
+            -2 => '=',
+            -1 => '-',
+            0 => '0',
+            1 => '1',
+            2 => '2',
+            _ => panic!(),
+        });
+    }
+
+    res.chars().rev().collect()
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: u8 = 25;
+
+    type Input = Vec<i64>;
+    type Answer1 = String;
+    type Answer2 = String;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: include_str!("../example"),
+        part1: Some("2=-1=0"),
+        part2: None,
+    }];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input, parse_snafu)
+    }
+
+    fn part1(numbers: &Self::Input) -> Result<Self::Answer1> {
+        Ok(to_snafu(numbers.iter().sum()))
+    }
+
+    // The last day only has one puzzle
+    fn part2(_numbers: &Self::Input) -> Result<Self::Answer2> {
+        Err(Unsolved.into())
+    }
+}
@@ -0,0 +1,477 @@
+// https://adventofcode.com/2022/day/3
+
+use std::{
+    ops::{BitAnd, BitOr, Sub},
+    str::FromStr,
+};
+
+use anyhow::anyhow;
+use aoc_common::{char_at, parse_lines, Example, ParseError, Result, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Item {
+    id: char,
+}
+
+impl Item {
+    pub fn id(&self) -> char {
+        self.id
+    }
+
+    pub fn priority(&self) -> u32 {
+        match self.id {
+            'a'..='z' => self.id as u32 - 'a' as u32 + 1,
+            'A'..='Z' => self.id as u32 - 'A' as u32 + 27,
+            _ => unreachable!("Items are letters"),
+        }
+    }
+
+    /// Item of a priority, from 1 to 52
+    pub fn from_priority(priority: u32) -> Option<Self> {
+        let id = match priority {
+            1..=26 => b'a' + (priority - 1) as u8,
+            27..=52 => b'A' + (priority - 27) as u8,
+            _ => return None,
+        };
+        Some(Item { id: id as char })
+    }
+}
+
+impl TryFrom<char> for Item {
+    type Error = char;
+
+    fn try_from(id: char) -> Result<Self, Self::Error> {
+        if id.is_ascii_alphabetic() {
+            Ok(Item { id })
+        } else {
+            Err(id)
+        }
+    }
+}
+
+/// Set of item types, one bit per priority
+///
+/// ```
+/// use day3::ItemSet;
+///
+/// let a: ItemSet = "vJrwpWtwJgWr".parse().unwrap();
+/// let b: ItemSet = "hcsFMMfFFhFp".parse().unwrap();
+/// let common = a & b;
+/// assert_eq!(common.len(), 1);
+/// assert_eq!(common.first().unwrap().id(), 'p');
+/// assert_eq!((a | b).len(), a.len() + b.len() - 1);
+/// assert!(!(a - b).contains('p'.try_into().unwrap()));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ItemSet(u64);
+
+impl ItemSet {
+    pub const EMPTY: ItemSet = ItemSet(0);
+
+    fn bit(item: Item) -> u64 {
+        1 << (item.priority() - 1)
+    }
+
+    pub fn insert(&mut self, item: Item) {
+        self.0 |= ItemSet::bit(item);
+    }
+
+    pub fn remove(&mut self, item: Item) {
+        self.0 &= !ItemSet::bit(item);
+    }
+
+    pub fn contains(&self, item: Item) -> bool {
+        self.0 & ItemSet::bit(item) != 0
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn union(self, other: ItemSet) -> ItemSet {
+        ItemSet(self.0 | other.0)
+    }
+
+    pub fn intersection(self, other: ItemSet) -> ItemSet {
+        ItemSet(self.0 & other.0)
+    }
+
+    /// Items of `self` that are not in `other`
+    pub fn difference(self, other: ItemSet) -> ItemSet {
+        ItemSet(self.0 & !other.0)
+    }
+
+    /// Item with the lowest priority
+    pub fn first(&self) -> Option<Item> {
+        Item::from_priority(self.0.trailing_zeros() + 1)
+    }
+
+    /// Items from the lowest priority
+    pub fn iter(&self) -> impl Iterator<Item = Item> {
+        let bits = self.0;
+        (1..=52)
+            .filter(move |priority| bits & (1 << (priority - 1)) != 0)
+            .filter_map(Item::from_priority)
+    }
+
+    /// Sum of the priorities of the items
+    pub fn priority(&self) -> u32 {
+        self.iter().map(|item| item.priority()).sum()
+    }
+}
+
+impl BitOr for ItemSet {
+    type Output = ItemSet;
+
+    fn bitor(self, other: ItemSet) -> ItemSet {
+        self.union(other)
+    }
+}
+
+impl BitAnd for ItemSet {
+    type Output = ItemSet;
+
+    fn bitand(self, other: ItemSet) -> ItemSet {
+        self.intersection(other)
+    }
+}
+
+impl Sub for ItemSet {
+    type Output = ItemSet;
+
+    fn sub(self, other: ItemSet) -> ItemSet {
+        self.difference(other)
+    }
+}
+
+impl FromIterator<Item> for ItemSet {
+    fn from_iter<I: IntoIterator<Item = Item>>(items: I) -> Self {
+        let mut set = ItemSet::EMPTY;
+        items.into_iter().for_each(|item| set.insert(item));
+        set
+    }
+}
+
+impl FromStr for ItemSet {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(parse_items(s)?.into_iter().collect())
+    }
+}
+
+fn parse_items(s: &str) -> Result<Vec<Item>, ParseError> {
+    s.char_indices()
+        .map(|(i, c)| {
+            Item::try_from(c).map_err(|_| ParseError::new(s, char_at(s, i), "expected a letter"))
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone)]
+pub struct Compartment {
+    content: Vec<Item>,
+    items: ItemSet,
+}
+
+impl Compartment {
+    pub fn new(content: Vec<Item>) -> Self {
+        let items = content.iter().copied().collect();
+        Compartment { content, items }
+    }
+
+    /// Items in the order they were packed
+    pub fn content(&self) -> &[Item] {
+        &self.content
+    }
+
+    /// Item types in the compartment
+    pub fn items(&self) -> ItemSet {
+        self.items
+    }
+
+    pub fn len(&self) -> usize {
+        self.content.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.content.is_empty()
+    }
+
+    /// Number of items of the type of `item`
+    pub fn count(&self, item: Item) -> usize {
+        self.content.iter().filter(|&&other| other == item).count()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Rucksack {
+    pub left: Compartment,
+    pub right: Compartment,
+}
+
+impl FromStr for Rucksack {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut items = parse_items(s)?;
+
+        if items.len() % 2 != 0 {
+            return Err(ParseError::new(s, s, "expected an even number of items"));
+        }
+
+        let right = items.split_off(items.len() / 2);
+        Ok(Self {
+            left: Compartment::new(items),
+            right: Compartment::new(right),
+        })
+    }
+}
+
+impl Rucksack {
+    /// Item types in both compartments
+    pub fn common_items(&self) -> ItemSet {
+        self.left.items() & self.right.items()
+    }
+
+    /// Item types in the rucksack
+    pub fn items(&self) -> ItemSet {
+        self.left.items() | self.right.items()
+    }
+
+    fn compartment(&self, side: Side) -> &Compartment {
+        match side {
+            Side::Left => &self.left,
+            Side::Right => &self.right,
+        }
+    }
+
+    /// Fewest item moves leaving no item type in both compartments, with the
+    /// same number of items in each, `None` when it cannot be done
+    ///
+    /// Each item type ends up whole in one compartment, the moves are picked
+    /// by going through the types while tracking the size of the left
+    /// compartment.
+    ///
+    /// ```
+    /// use day3::Rucksack;
+    ///
+    /// let rucksack: Rucksack = "vJrwpWtwJgWrhcsFMMfFFhFp".parse().unwrap();
+    /// let moves = rucksack.repack().unwrap();
+    /// assert_eq!(moves.iter().map(|m| m.count).sum::<usize>(), 2);
+    ///
+    /// let repacked = rucksack.apply(&moves);
+    /// assert!(repacked.common_items().is_empty());
+    /// assert_eq!(repacked.left.len(), repacked.right.len());
+    ///
+    /// let stuck: Rucksack = "aaaa".parse().unwrap();
+    /// assert_eq!(stuck.repack(), None);
+    /// ```
+    pub fn repack(&self) -> Option<Vec<Move>> {
+        let types: Vec<(Item, usize, usize)> = self
+            .items()
+            .iter()
+            .map(|item| (item, self.left.count(item), self.right.count(item)))
+            .collect();
+        let total = self.left.len() + self.right.len();
+        if !total.is_multiple_of(2) {
+            return None;
+        }
+
+        // costs[i][size]: fewest items moved for the first i types to leave
+        // `size` items in the left compartment, with the side of the type i - 1
+        let mut costs = vec![vec![None; total + 1]; types.len() + 1];
+        costs[0][0] = Some((0, Side::Left));
+        for (i, &(_, left, right)) in types.iter().enumerate() {
+            for size in 0..=total {
+                let Some((cost, _)) = costs[i][size] else {
+                    continue;
+                };
+                let choices = [
+                    (size + left + right, cost + right, Side::Left),
+                    (size, cost + left, Side::Right),
+                ];
+                for (size, cost, side) in choices {
+                    let best = &mut costs[i + 1][size];
+                    if best.is_none_or(|(best, _)| cost < best) {
+                        *best = Some((cost, side));
+                    }
+                }
+            }
+        }
+
+        costs[types.len()][total / 2]?;
+
+        let mut moves = Vec::new();
+        let mut size = total / 2;
+        for (i, &(item, left, right)) in types.iter().enumerate().rev() {
+            let (_, side) = costs[i + 1][size].unwrap();
+            let from = match side {
+                Side::Left => Side::Right,
+                Side::Right => Side::Left,
+            };
+            let count = self.compartment(from).count(item);
+            if count > 0 {
+                moves.push(Move { item, count, from });
+            }
+            if side == Side::Left {
+                size -= left + right;
+            }
+        }
+        moves.reverse();
+
+        Some(moves)
+    }
+
+    /// Rucksack with the items moved, they go at the end of their new compartment
+    pub fn apply(&self, moves: &[Move]) -> Rucksack {
+        let moved = |from: Side| -> ItemSet {
+            moves
+                .iter()
+                .filter(|m| m.from == from)
+                .map(|m| m.item)
+                .collect()
+        };
+        let (to_right, to_left) = (moved(Side::Left), moved(Side::Right));
+
+        // Items keep their order, the ones moving in go after the ones staying
+        let repack =
+            |stay: &Compartment, leaving: ItemSet, other: &Compartment, coming: ItemSet| {
+                let staying = stay.content.iter().filter(|&&item| !leaving.contains(item));
+                let moving = other.content.iter().filter(|&&item| coming.contains(item));
+                Compartment::new(staying.chain(moving).copied().collect())
+            };
+
+        Rucksack {
+            left: repack(&self.left, to_right, &self.right, to_left),
+            right: repack(&self.right, to_left, &self.left, to_right),
+        }
+    }
+}
+
+/// Compartment of a rucksack
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Side {
+    Left,
+    Right,
+}
+
+/// All the items of one type moved to the other compartment
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Move {
+    pub item: Item,
+    pub count: usize,
+    pub from: Side,
+}
+
+/// Moves fixing a whole list of rucksacks
+#[derive(Debug, Clone, PartialEq)]
+pub struct RepackPlan {
+    /// Moves with the index of their rucksack
+    pub moves: Vec<(usize, Move)>,
+    /// Rucksacks that cannot be fixed, they are left as they are
+    pub stuck: Vec<usize>,
+    /// Priority of the items in both compartments, before and after the moves
+    pub before: u32,
+    pub after: u32,
+}
+
+pub fn plan_repacking(rucksacks: &[Rucksack]) -> RepackPlan {
+    let mut plan = RepackPlan {
+        moves: Vec::new(),
+        stuck: Vec::new(),
+        before: 0,
+        after: 0,
+    };
+
+    for (i, rucksack) in rucksacks.iter().enumerate() {
+        let priority = rucksack.common_items().priority();
+        plan.before += priority;
+        match rucksack.repack() {
+            Some(moves) => plan.moves.extend(moves.into_iter().map(|m| (i, m))),
+            None => {
+                plan.stuck.push(i);
+                plan.after += priority;
+            }
+        }
+    }
+
+    plan
+}
+
+/// Elves carrying their rucksacks together
+pub struct Group<'a> {
+    rucksacks: &'a [Rucksack],
+}
+
+impl<'a> Group<'a> {
+    pub fn new(rucksacks: &'a [Rucksack]) -> Self {
+        Self { rucksacks }
+    }
+
+    /// Split the rucksacks in groups of `size`, the last group is smaller when
+    /// they do not divide evenly
+    pub fn split(rucksacks: &'a [Rucksack], size: usize) -> impl Iterator<Item = Group<'a>> {
+        rucksacks.chunks(size.max(1)).map(Group::new)
+    }
+
+    /// Item types carried by every elf of the group
+    pub fn tag_items(&self) -> ItemSet {
+        self.rucksacks
+            .iter()
+            .map(Rucksack::items)
+            .reduce(ItemSet::intersection)
+            .unwrap_or_default()
+    }
+
+    /// The item type carried by every elf, `None` unless there is exactly one
+    pub fn tag_item(&self) -> Option<Item> {
+        let tags = self.tag_items();
+        tags.first().filter(|_| tags.len() == 1)
+    }
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+
+    type Input = Vec<Rucksack>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: include_str!("../example"),
+        part1: Some("157"),
+        part2: Some("70"),
+    }];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input, str::parse)
+    }
+
+    fn part1(rucksacks: &Self::Input) -> Result<Self::Answer1> {
+        let sum_of_commom = rucksacks
+            .iter()
+            .map(|rucksack| rucksack.common_items().priority())
+            .sum();
+
+        Ok(sum_of_commom)
+    }
+
+    fn part2(rucksacks: &Self::Input) -> Result<Self::Answer2> {
+        if rucksacks.len() % 3 != 0 {
+            return Err(anyhow!("Expected groups of 3 elves"));
+        }
+
+        let sum_of_tags = Group::split(rucksacks, 3)
+            .map(|group| group.tag_items().priority())
+            .sum();
+
+        Ok(sum_of_tags)
+    }
+}
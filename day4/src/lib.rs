@@ -0,0 +1,457 @@
+// https://adventofcode.com/2022/day/4
+
+use std::{
+    cmp::Reverse,
+    collections::BinaryHeap,
+    fmt::{self, Display},
+    ops::RangeInclusive,
+    str::FromStr,
+};
+
+use aoc_common::{
+    bfs, next_token, parse_lines, parse_token, Example, ParseError, Result, Solution,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Pair {
+    pub start: u32,
+    pub end: u32,
+}
+
+impl FromStr for Pair {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut items = s.split('-');
+
+        Ok(Self {
+            start: parse_token(s, next_token(s, &mut items, "the first section")?)?,
+            end: parse_token(s, next_token(s, &mut items, "the last section")?)?,
+        })
+    }
+}
+
+impl Pair {
+    pub fn sections(&self) -> RangeInclusive<u32> {
+        self.start..=self.end
+    }
+}
+
+#[derive(Debug)]
+pub struct Sections(pub Pair, pub Pair);
+
+impl Sections {
+    pub fn fully_overlaps(&self) -> bool {
+        let (a, b) = (IntervalSet::from(&self.0), IntervalSet::from(&self.1));
+        a.is_superset(&b) || b.is_superset(&a)
+    }
+
+    pub fn overlaps(&self) -> bool {
+        IntervalSet::from(&self.0).overlaps(&IntervalSet::from(&self.1))
+    }
+}
+
+/// Set of sections, kept as sorted ranges that neither overlap nor touch
+///
+/// ```
+/// use day4::IntervalSet;
+///
+/// let a: IntervalSet = [2..=4, 6..=8, 5..=5].into_iter().collect();
+/// let b: IntervalSet = [3..=7].into_iter().collect();
+/// assert_eq!(a.to_string(), "2-8");
+/// assert_eq!(a.difference(&b).to_string(), "2,8");
+/// assert_eq!((a.len(), a.intersection(&b).len()), (7, 5));
+/// assert!(a.is_superset(&b) && !b.is_superset(&a));
+///
+/// let twice = IntervalSet::covered_at_least([1..=5, 3..=9, 4..=4], 2);
+/// assert_eq!(twice.to_string(), "3-5");
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet {
+    ranges: Vec<(u32, u32)>,
+}
+
+impl IntervalSet {
+    pub fn new() -> Self {
+        IntervalSet::default()
+    }
+
+    /// Build from sorted ranges, merging the ones that overlap or touch
+    fn merged(sorted: impl IntoIterator<Item = (u32, u32)>) -> Self {
+        let mut ranges: Vec<(u32, u32)> = Vec::new();
+        for (start, end) in sorted {
+            if start > end {
+                continue;
+            }
+            match ranges.last_mut() {
+                Some(last) if start <= last.1.saturating_add(1) => last.1 = last.1.max(end),
+                _ => ranges.push((start, end)),
+            }
+        }
+        IntervalSet { ranges }
+    }
+
+    pub fn insert(&mut self, sections: RangeInclusive<u32>) {
+        *self = self.union(&IntervalSet::from(sections));
+    }
+
+    /// Sorted ranges of the set
+    pub fn ranges(&self) -> impl Iterator<Item = RangeInclusive<u32>> + '_ {
+        self.ranges.iter().map(|&(start, end)| start..=end)
+    }
+
+    /// Number of sections in the set
+    pub fn len(&self) -> u64 {
+        self.ranges
+            .iter()
+            .map(|&(start, end)| (end - start) as u64 + 1)
+            .sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// First and last sections, `None` when empty
+    pub fn bounds(&self) -> Option<RangeInclusive<u32>> {
+        Some(self.ranges.first()?.0..=self.ranges.last()?.1)
+    }
+
+    pub fn contains(&self, section: u32) -> bool {
+        let i = self.ranges.partition_point(|&(_, end)| end < section);
+        self.ranges
+            .get(i)
+            .is_some_and(|&(start, _)| start <= section)
+    }
+
+    pub fn union(&self, other: &IntervalSet) -> IntervalSet {
+        let mut all: Vec<(u32, u32)> = self.ranges.iter().chain(&other.ranges).copied().collect();
+        all.sort_unstable();
+        IntervalSet::merged(all)
+    }
+
+    pub fn intersection(&self, other: &IntervalSet) -> IntervalSet {
+        let mut ranges = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while let (Some(&a), Some(&b)) = (self.ranges.get(i), other.ranges.get(j)) {
+            let (start, end) = (a.0.max(b.0), a.1.min(b.1));
+            if start <= end {
+                ranges.push((start, end));
+            }
+            // The range ending first cannot meet the next ones of the other set
+            if a.1 < b.1 {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        IntervalSet { ranges }
+    }
+
+    /// Sections of `self` that are not in `other`
+    pub fn difference(&self, other: &IntervalSet) -> IntervalSet {
+        let mut ranges = Vec::new();
+        let mut others = other.ranges.iter().peekable();
+        for &(start, end) in &self.ranges {
+            let mut start = Some(start);
+            while let (Some(from), Some(&&(cut_start, cut_end))) = (start, others.peek()) {
+                if cut_end < from {
+                    others.next();
+                    continue;
+                }
+                if cut_start > end {
+                    break;
+                }
+                if cut_start > from {
+                    ranges.push((from, cut_start - 1));
+                }
+                start = cut_end.checked_add(1).filter(|&next| next <= end);
+                if cut_end <= end {
+                    others.next();
+                }
+            }
+            if let Some(from) = start {
+                ranges.push((from, end));
+            }
+        }
+        IntervalSet { ranges }
+    }
+
+    pub fn overlaps(&self, other: &IntervalSet) -> bool {
+        !self.intersection(other).is_empty()
+    }
+
+    /// Whether every section of `other` is in `self`
+    pub fn is_superset(&self, other: &IntervalSet) -> bool {
+        other.difference(self).is_empty()
+    }
+
+    /// Sections covered by `count` of the ranges or more
+    pub fn covered_at_least(
+        ranges: impl IntoIterator<Item = RangeInclusive<u32>>,
+        count: usize,
+    ) -> IntervalSet {
+        // +1 where a range starts, -1 after it ends
+        let mut events: Vec<(u64, isize)> = Vec::new();
+        for range in ranges.into_iter().filter(|range| !range.is_empty()) {
+            events.push((*range.start() as u64, 1));
+            events.push((*range.end() as u64 + 1, -1));
+        }
+        events.sort_unstable();
+
+        let mut covered = Vec::new();
+        let mut depth = 0;
+        for (i, &(at, change)) in events.iter().enumerate() {
+            depth += change;
+            let next = events.get(i + 1).map(|&(next, _)| next);
+            if depth >= count as isize && next.is_some_and(|next| next > at) {
+                covered.push((at as u32, (next.unwrap() - 1) as u32));
+            }
+        }
+        IntervalSet::merged(covered)
+    }
+}
+
+impl From<RangeInclusive<u32>> for IntervalSet {
+    fn from(sections: RangeInclusive<u32>) -> Self {
+        IntervalSet::merged([(*sections.start(), *sections.end())])
+    }
+}
+
+impl From<&Pair> for IntervalSet {
+    fn from(pair: &Pair) -> Self {
+        IntervalSet::from(pair.sections())
+    }
+}
+
+impl FromIterator<RangeInclusive<u32>> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<u32>>>(ranges: I) -> Self {
+        let mut all: Vec<(u32, u32)> = ranges
+            .into_iter()
+            .map(|range| (*range.start(), *range.end()))
+            .collect();
+        all.sort_unstable();
+        IntervalSet::merged(all)
+    }
+}
+
+/// Ranges as in the assignments, `2-4,6,8-9`
+impl Display for IntervalSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, &(start, end)) in self.ranges.iter().enumerate() {
+            if i > 0 {
+                f.write_str(",")?;
+            }
+            if start == end {
+                write!(f, "{}", start)?;
+            } else {
+                write!(f, "{}-{}", start, end)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Sections {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut pairs = s.split(',');
+        let mut next_pair = |what| {
+            let pair = next_token(s, &mut pairs, what)?;
+            Pair::from_str(pair).map_err(|e| e.within(s, pair))
+        };
+
+        Ok(Self(
+            next_pair("the first elf")?,
+            next_pair("the second elf")?,
+        ))
+    }
+}
+
+/// Sections assigned to one elf of a line
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Assignment {
+    /// Line of the pair of elves, from 0
+    pub line: usize,
+    /// 0 for the first elf of the line, 1 for the second
+    pub elf: usize,
+    pub pair: Pair,
+}
+
+/// Assignments of every line, linked when they share a section
+///
+/// ```
+/// use aoc_common::Solution;
+/// use day4::{Day4, OverlapGraph};
+///
+/// let sections = Day4::parse("2-4,6-8\n2-3,4-5\n5-7,7-9\n9-9,10-12").unwrap();
+/// let graph = OverlapGraph::new(&sections);
+/// assert_eq!(graph.neighbours(0), [2, 3]);
+/// assert_eq!(graph.components(), [vec![0, 1, 2, 3, 4, 5, 6], vec![7]]);
+/// assert_eq!(graph.max_overlap(), Some((3, 7)));
+/// assert_eq!(graph.reassign().len(), 3);
+/// ```
+#[derive(Debug, Clone)]
+pub struct OverlapGraph {
+    pub assignments: Vec<Assignment>,
+    edges: Vec<Vec<usize>>,
+}
+
+impl OverlapGraph {
+    pub fn new(sections: &[Sections]) -> Self {
+        let assignments: Vec<Assignment> = sections
+            .iter()
+            .enumerate()
+            .flat_map(|(line, Sections(first, second))| {
+                [(0, *first), (1, *second)].map(|(elf, pair)| Assignment { line, elf, pair })
+            })
+            .collect();
+
+        // Sorted by start, an assignment overlaps the next ones starting
+        // before its end. Assignments with no section overlap nothing.
+        let mut order: Vec<usize> = (0..assignments.len())
+            .filter(|&i| assignments[i].pair.start <= assignments[i].pair.end)
+            .collect();
+        order.sort_by_key(|&i| assignments[i].pair.start);
+
+        let mut edges = vec![Vec::new(); assignments.len()];
+        for (k, &i) in order.iter().enumerate() {
+            let end = assignments[i].pair.end;
+            for &j in order[k + 1..]
+                .iter()
+                .take_while(|&&j| assignments[j].pair.start <= end)
+            {
+                edges[i].push(j);
+                edges[j].push(i);
+            }
+        }
+        edges.iter_mut().for_each(|edges| edges.sort_unstable());
+
+        OverlapGraph { assignments, edges }
+    }
+
+    pub fn len(&self) -> usize {
+        self.assignments.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.assignments.is_empty()
+    }
+
+    /// Assignments sharing a section with the assignment `i`
+    pub fn neighbours(&self, i: usize) -> &[usize] {
+        &self.edges[i]
+    }
+
+    pub fn edge_count(&self) -> usize {
+        self.edges.iter().map(Vec::len).sum::<usize>() / 2
+    }
+
+    /// Groups of assignments linked by overlaps, each sorted, ordered by their
+    /// first assignment
+    pub fn components(&self) -> Vec<Vec<usize>> {
+        let mut seen = vec![false; self.len()];
+        let mut components = Vec::new();
+
+        for start in 0..self.len() {
+            if seen[start] {
+                continue;
+            }
+            let paths = bfs(start, |&i: &usize| self.edges[i].clone(), |_| false);
+            let mut component: Vec<usize> = paths.iter().map(|(&i, _)| i).collect();
+            component.sort_unstable();
+            component.iter().for_each(|&i| seen[i] = true);
+            components.push(component);
+        }
+        components
+    }
+
+    /// Most assignments sharing one section, with the first section where it happens
+    pub fn max_overlap(&self) -> Option<(usize, u32)> {
+        // +1 where an assignment starts, -1 after it ends, the ends first on ties
+        let mut events: Vec<(u64, isize)> = Vec::new();
+        for Assignment { pair, .. } in self
+            .assignments
+            .iter()
+            .filter(|a| a.pair.start <= a.pair.end)
+        {
+            events.push((pair.start as u64, 1));
+            events.push((pair.end as u64 + 1, -1));
+        }
+        events.sort_unstable();
+
+        let mut depth = 0;
+        let mut most = None;
+        for (at, change) in events {
+            depth += change;
+            if most.is_none_or(|(most, _)| depth as usize > most) {
+                most = Some((depth as usize, at as u32));
+            }
+        }
+        most
+    }
+
+    /// Greedy proposal splitting the assignments into crews that never work on
+    /// the same section, using as few crews as the maximum overlap
+    ///
+    /// Assignments are handed out by start, to the crew that has been free the
+    /// longest, or to a new crew when they are all busy.
+    pub fn reassign(&self) -> Vec<Vec<usize>> {
+        let mut order: Vec<usize> = (0..self.len())
+            .filter(|&i| self.assignments[i].pair.start <= self.assignments[i].pair.end)
+            .collect();
+        order.sort_by_key(|&i| self.assignments[i].pair.start);
+
+        let mut crews: Vec<Vec<usize>> = Vec::new();
+        // Last section of each crew, the earliest on top
+        let mut busy: BinaryHeap<Reverse<(u32, usize)>> = BinaryHeap::new();
+        for i in order {
+            let pair = self.assignments[i].pair;
+            let crew = match busy.peek() {
+                Some(&Reverse((end, crew))) if end < pair.start => {
+                    busy.pop();
+                    crew
+                }
+                _ => {
+                    crews.push(Vec::new());
+                    crews.len() - 1
+                }
+            };
+            crews[crew].push(i);
+            busy.push(Reverse((pair.end, crew)));
+        }
+        crews
+    }
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+
+    type Input = Vec<Sections>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: include_str!("../example"),
+        part1: Some("2"),
+        part2: Some("4"),
+    }];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input, Sections::from_str)
+    }
+
+    fn part1(sections: &Self::Input) -> Result<Self::Answer1> {
+        Ok(sections
+            .iter()
+            .filter(|section| section.fully_overlaps())
+            .count())
+    }
+
+    fn part2(sections: &Self::Input) -> Result<Self::Answer2> {
+        Ok(sections.iter().filter(|section| section.overlaps()).count())
+    }
+}
@@ -1,73 +1,57 @@
-use std::{
-    fs::File,
-    io::{BufRead, BufReader},
-    str::FromStr,
-};
+use std::fs;
 
 use anyhow::Result;
-
-#[derive(Debug)]
-struct Pair {
-    start: u32,
-    end: u32,
-}
-
-impl FromStr for Pair {
-    type Err = anyhow::Error;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut items = s.split("-");
-
-        Ok(Self {
-            start: items.next().unwrap().parse()?,
-            end: items.next().unwrap().parse()?,
-        })
-    }
-}
-
-#[derive(Debug)]
-struct Sections(Pair, Pair);
-
-impl Sections {
-    fn fully_overlaps(&self) -> bool {
-        (self.0.start <= self.1.start && self.0.end >= self.1.end)
-            || (self.0.start >= self.1.start && self.0.end <= self.1.end)
-    }
-
-    fn overlaps(&self) -> bool {
-        (self.0.start..=self.0.end).contains(&self.1.start)
-            || (self.1.start..=self.1.end).contains(&self.0.start)
-    }
-}
-
-impl FromStr for Sections {
-    type Err = anyhow::Error;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut pairs = s.split(",");
-        Ok(Self(
-            Pair::from_str(pairs.next().unwrap())?,
-            Pair::from_str(pairs.next().unwrap())?,
-        ))
-    }
-}
+use aoc_common::Solution;
+use day4::{Day4, IntervalSet, OverlapGraph};
 
 fn main() -> Result<()> {
-    let input = BufReader::new(File::open("input")?)
-        .lines()
-        .map(|line| Sections::from_str(&line.unwrap()).unwrap());
-
-    let full_overlap = input.filter(|section| section.fully_overlaps()).count();
-
-    let input = BufReader::new(File::open("input")?)
-        .lines()
-        .map(|line| Sections::from_str(&line.unwrap()).unwrap());
+    let sections = Day4::parse(&fs::read_to_string("input")?)?;
 
-    let some_overlap = input.filter(|section| section.overlaps()).count();
+    let full_overlap = Day4::part1(&sections)?;
+    let some_overlap = Day4::part2(&sections)?;
 
     println!(
         "Full overlaps: {}, Some overlaps: {}",
         full_overlap, some_overlap
     );
+
+    let assignments = || {
+        sections
+            .iter()
+            .flat_map(|s| [s.0.sections(), s.1.sections()])
+    };
+    let covered: IntervalSet = assignments().collect();
+    let uncovered = covered
+        .bounds()
+        .map_or_else(IntervalSet::new, IntervalSet::from)
+        .difference(&covered);
+    let crowded = IntervalSet::covered_at_least(assignments(), 3);
+    println!(
+        "Sections covered by no elf: {} ({})",
+        uncovered.len(),
+        uncovered
+    );
+    println!(
+        "Sections covered by more than two elves: {} ({})",
+        crowded.len(),
+        crowded
+    );
+
+    let graph = OverlapGraph::new(&sections);
+    let components = graph.components();
+    println!(
+        "{} assignments, {} overlaps, {} clusters (largest: {} assignments)",
+        graph.len(),
+        graph.edge_count(),
+        components.len(),
+        components.iter().map(Vec::len).max().unwrap_or(0)
+    );
+    if let Some((most, section)) = graph.max_overlap() {
+        println!("At most {} assignments share section {}", most, section);
+    }
+    println!(
+        "Reassigning needs {} crews without overlaps",
+        graph.reassign().len()
+    );
     Ok(())
 }
@@ -0,0 +1,572 @@
+// https://adventofcode.com/2022/day/5
+
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+use aoc_common::{
+    astar, char_at, keyword, next_token, parse_lines, parse_token, Example, ParseError, Result,
+    Solution,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Move {
+    pub times: usize,
+    pub from: usize,
+    pub to: usize,
+}
+
+/// Move in the format of the puzzle, `move 1 from 2 to 1`
+impl Display for Move {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "move {} from {} to {}", self.times, self.from, self.to)
+    }
+}
+
+impl FromStr for Move {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut inputs = s.split(' ');
+
+        keyword(s, &mut inputs, "move")?;
+        let times = parse_token(s, next_token(s, &mut inputs, "a number of crates")?)?;
+        keyword(s, &mut inputs, "from")?;
+        let from = parse_token(s, next_token(s, &mut inputs, "a stack number")?)?;
+        keyword(s, &mut inputs, "to")?;
+        let to = parse_token(s, next_token(s, &mut inputs, "a stack number")?)?;
+
+        Ok(Self { times, from, to })
+    }
+}
+
+/// Reason a crane cannot do a move
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MoveError {
+    /// The stack number is not in the drawing
+    NoStack(usize),
+    /// The stack holds fewer crates than the move takes
+    NotEnoughCrates { stack: usize, crates: usize },
+}
+
+impl Display for MoveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MoveError::NoStack(stack) => write!(f, "there is no stack {}", stack),
+            MoveError::NotEnoughCrates { stack, crates } => {
+                write!(f, "stack {} only holds {} crates", stack, crates)
+            }
+        }
+    }
+}
+
+impl std::error::Error for MoveError {}
+
+/// Move that cannot be done, located in the input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidMove {
+    /// Line of the move, from 1
+    pub line: usize,
+    /// Position of the move in the list, from 0
+    pub step: usize,
+    pub mv: Move,
+    pub error: MoveError,
+}
+
+impl Display for InvalidMove {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Line {}: cannot {}, {}", self.line, self.mv, self.error)
+    }
+}
+
+impl std::error::Error for InvalidMove {}
+
+/// Check that the stacks of a move exist and hold enough crates
+pub fn check_move(stacks: &Stacks, mv: &Move) -> Result<(), MoveError> {
+    let stack = |number: usize| {
+        number
+            .checked_sub(1)
+            .and_then(|i| stacks.0.get(i))
+            .ok_or(MoveError::NoStack(number))
+    };
+
+    let crates = stack(mv.from)?.len();
+    stack(mv.to)?;
+    if crates < mv.times {
+        return Err(MoveError::NotEnoughCrates {
+            stack: mv.from,
+            crates,
+        });
+    }
+    Ok(())
+}
+
+/// Model of crane, moving the crates its own way
+pub trait Crane {
+    /// Most crates lifted at once, keeping their order
+    fn capacity(&self) -> usize;
+
+    /// Do a move, the stacks are left untouched if it is invalid
+    fn execute(&self, stacks: &mut Stacks, mv: &Move) -> Result<(), MoveError> {
+        check_move(stacks, mv)?;
+
+        let mut left = mv.times;
+        while left > 0 {
+            let lift = left.min(self.capacity());
+            let from = &mut stacks.0[mv.from - 1];
+            let crates = from.split_off(from.len() - lift);
+            stacks.0[mv.to - 1].extend(crates);
+            left -= lift;
+        }
+        Ok(())
+    }
+}
+
+/// Moves one crate at a time
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CrateMover9000;
+
+impl Crane for CrateMover9000 {
+    fn capacity(&self) -> usize {
+        1
+    }
+}
+
+/// Moves all the crates of a move at once
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CrateMover9001;
+
+impl Crane for CrateMover9001 {
+    fn capacity(&self) -> usize {
+        usize::MAX
+    }
+}
+
+/// Moves up to `capacity` crates at once, in as many lifts as needed
+///
+/// ```
+/// use day5::{Crane, LimitedCrane, Move, Stacks};
+///
+/// let mut stacks = Stacks(vec![vec!['A', 'B', 'C', 'D', 'E'], vec![]]);
+/// let crane = LimitedCrane::new(2).unwrap();
+/// crane.execute(&mut stacks, &Move { times: 5, from: 1, to: 2 }).unwrap();
+/// assert_eq!(stacks.0[1], ['D', 'E', 'B', 'C', 'A']);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct LimitedCrane {
+    capacity: usize,
+}
+
+impl LimitedCrane {
+    /// `None` for a crane that cannot lift anything
+    pub fn new(capacity: usize) -> Option<Self> {
+        (capacity > 0).then_some(LimitedCrane { capacity })
+    }
+}
+
+impl Crane for LimitedCrane {
+    fn capacity(&self) -> usize {
+        self.capacity
+    }
+}
+
+/// Crates of each stack, bottom first
+///
+/// Parsed from and displayed as the drawing of the puzzle:
+///
+/// ```
+/// use day5::Stacks;
+///
+/// let drawing = "    [D]\n[N] [C]\n[Z] [M] [P]\n 1   2   3";
+/// let stacks: Stacks = drawing.parse().unwrap();
+/// assert_eq!(stacks.0, [vec!['Z', 'N'], vec!['M', 'C', 'D'], vec!['P']]);
+/// assert_eq!(stacks.top_crates(), "NDP");
+/// assert_eq!(stacks.to_string(), drawing);
+///
+/// let error = "[A]\n[B]\n a".parse::<Stacks>().unwrap_err();
+/// assert_eq!((error.line, error.column), (3, 2));
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Stacks(pub Vec<Vec<char>>);
+
+impl Stacks {
+    /// Crate on top of each stack, a space for the empty ones
+    pub fn top_crates(&self) -> String {
+        self.0
+            .iter()
+            .map(|stack| stack.last().copied().unwrap_or(' '))
+            .collect()
+    }
+}
+
+impl FromStr for Stacks {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines: Vec<&str> = s.lines().collect();
+        let footer = lines
+            .pop()
+            .ok_or_else(|| ParseError::missing(s, "expected a drawing of the stacks"))?;
+
+        // The crates are drawn above the number of their stack
+        let mut columns = Vec::new();
+        for (i, number) in footer.split_whitespace().enumerate() {
+            let parsed: usize = parse_token(footer, number).map_err(|e| e.within(s, footer))?;
+            if parsed != i + 1 {
+                return Err(
+                    ParseError::new(footer, number, format!("expected stack {}", i + 1))
+                        .within(s, footer),
+                );
+            }
+            columns.push(number.as_ptr() as usize - footer.as_ptr() as usize);
+        }
+        if columns.is_empty() {
+            return Err(
+                ParseError::missing(footer, "expected the numbers of the stacks").within(s, footer),
+            );
+        }
+
+        let mut stacks = vec![Vec::new(); columns.len()];
+        for (height, line) in lines.into_iter().rev().enumerate() {
+            for (stack, &column) in stacks.iter_mut().zip(&columns) {
+                let c = match line.get(column..).and_then(|rest| rest.chars().next()) {
+                    None | Some(' ') => continue,
+                    Some(c) => c,
+                };
+                let error =
+                    |message| ParseError::new(line, char_at(line, column), message).within(s, line);
+
+                let cell = column
+                    .checked_sub(1)
+                    .and_then(|start| line.get(start..column + c.len_utf8() + 1));
+                if !cell.is_some_and(|cell| cell.starts_with('[') && cell.ends_with(']')) {
+                    return Err(error("expected a crate like `[A]`"));
+                }
+                if stack.len() < height {
+                    return Err(error("expected the crate to sit on another one"));
+                }
+                stack.push(c);
+            }
+        }
+
+        Ok(Stacks(stacks))
+    }
+}
+/// Drawing without trailing spaces, the stacks are numbered below the crates
+impl Display for Stacks {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let height = self.0.iter().map(Vec::len).max().unwrap_or(0);
+        for level in (0..height).rev() {
+            let row: Vec<String> = self
+                .0
+                .iter()
+                .map(|stack| {
+                    stack
+                        .get(level)
+                        .map_or("   ".to_string(), |c| format!("[{}]", c))
+                })
+                .collect();
+            writeln!(f, "{}", row.join(" ").trim_end())?;
+        }
+
+        let numbers: Vec<String> = (1..=self.0.len()).map(|i| format!(" {} ", i)).collect();
+        write!(f, "{}", numbers.join(" ").trim_end())
+    }
+}
+
+/// Drawing of the stacks and the moves of the crane
+#[derive(Debug, Clone)]
+pub struct Procedure {
+    pub stacks: Stacks,
+    pub moves: Vec<Move>,
+    /// Line of the first move in the input, from 1
+    pub first_line: usize,
+}
+
+impl Procedure {
+    /// Stacks once `crane` did every move, or the first move it cannot do
+    ///
+    /// ```
+    /// use aoc_common::Solution;
+    /// use day5::{CrateMover9000, Day5, MoveError};
+    ///
+    /// let input = "[A]\n 1   2\n\nmove 1 from 1 to 2\nmove 2 from 2 to 1\n";
+    /// let error = Day5::parse(input).unwrap().dry_run(&CrateMover9000).unwrap_err();
+    /// assert_eq!((error.line, error.step), (5, 1));
+    /// assert_eq!(error.error, MoveError::NotEnoughCrates { stack: 2, crates: 1 });
+    /// assert_eq!(
+    ///     error.to_string(),
+    ///     "Line 5: cannot move 2 from 2 to 1, stack 2 only holds 1 crates"
+    /// );
+    /// ```
+    pub fn dry_run(&self, crane: &dyn Crane) -> Result<Stacks, InvalidMove> {
+        let mut stacks = self.stacks.clone();
+        for (step, mv) in self.moves.iter().enumerate() {
+            crane
+                .execute(&mut stacks, mv)
+                .map_err(|error| InvalidMove {
+                    line: self.first_line + step,
+                    step,
+                    mv: *mv,
+                    error,
+                })?;
+        }
+        Ok(stacks)
+    }
+}
+
+/// Crates of each stack already where `target` wants them, from the bottom
+fn settled(stacks: &Stacks, target: &Stacks) -> Vec<usize> {
+    stacks
+        .0
+        .iter()
+        .zip(&target.0)
+        .map(|(stack, target)| stack.iter().zip(target).take_while(|(a, b)| a == b).count())
+        .collect()
+}
+
+/// Whether both yards have as many stacks and the same crates
+fn same_crates(stacks: &Stacks, target: &Stacks) -> bool {
+    let sorted = |stacks: &Stacks| {
+        let mut crates: Vec<char> = stacks.0.iter().flatten().copied().collect();
+        crates.sort_unstable();
+        crates
+    };
+    stacks.0.len() == target.0.len() && sorted(stacks) == sorted(target)
+}
+
+/// Largest lift from `from` to `to` that lays the next crates wanted on `to`,
+/// leaving the settled crates of `from` alone
+fn placement(stacks: &Stacks, target: &Stacks, crane: &dyn Crane, from: usize, to: usize) -> Move {
+    let (from_settled, to_settled) = (settled(stacks, target)[from], stacks.0[to].len());
+    let loose = stacks.0[from].len() - from_settled;
+    let wanted = &target.0[to][to_settled..];
+
+    (1..=loose.min(wanted.len()))
+        .rev()
+        .map(|times| Move {
+            times,
+            from: from + 1,
+            to: to + 1,
+        })
+        .find(|mv| {
+            let mut moved = stacks.clone();
+            crane.execute(&mut moved, mv).is_ok() && moved.0[to][to_settled..] == wanted[..mv.times]
+        })
+        .expect("the wanted crate is on top")
+}
+
+/// Moves arranging the `from` stacks as `target` with `crane`, `None` when
+/// the crates differ or cannot be rearranged
+///
+/// The crates are laid on their final stack from the bottom, several at once
+/// when the crane lays them in the right order. The crates above a buried one
+/// are moved to a third stack, so there must be at least 3 stacks as soon as
+/// a crate is buried. This is fast for any yard, the moves are few but not
+/// always the fewest, see [`shortest_moves`].
+///
+/// ```
+/// use day5::{plan_moves, CrateMover9000, CrateMover9001, Move, Procedure, Stacks};
+///
+/// let from = Stacks(vec![vec!['Z', 'N'], vec!['M', 'C', 'D'], vec!['P']]);
+/// let target = Stacks(vec![vec!['C'], vec!['M'], vec!['P', 'D', 'N', 'Z']]);
+///
+/// let moves = plan_moves(&from, &target, &CrateMover9000).unwrap();
+/// let text: Vec<String> = moves.iter().map(Move::to_string).collect();
+/// assert_eq!(text, ["move 1 from 2 to 3", "move 2 from 1 to 3", "move 1 from 2 to 1"]);
+///
+/// let moves = plan_moves(&from, &target, &CrateMover9001).unwrap();
+/// let procedure = Procedure { stacks: from, moves, first_line: 1 };
+/// assert_eq!(procedure.dry_run(&CrateMover9001), Ok(target));
+/// ```
+pub fn plan_moves(from: &Stacks, target: &Stacks, crane: &dyn Crane) -> Option<Vec<Move>> {
+    if !same_crates(from, target) {
+        return None;
+    }
+
+    let mut stacks = from.clone();
+    let mut moves = Vec::new();
+    loop {
+        let settled = settled(&stacks, target);
+        let loose = |s: usize| stacks.0[s].len() - settled[s];
+        let incomplete: Vec<usize> = (0..stacks.0.len())
+            .filter(|&s| settled[s] < target.0[s].len())
+            .collect();
+        if incomplete.is_empty() {
+            // Every crate is settled, as both yards have the same crates
+            return Some(moves);
+        }
+
+        // Stack to dump loose crates on, away from `busy`: one with loose crates
+        // already, else a complete one, else one waiting for crates
+        let spare = |busy: [usize; 2]| {
+            (0..stacks.0.len())
+                .filter(|s| !busy.contains(s))
+                .min_by_key(|&s| (loose(s) == 0, settled[s] < target.0[s].len()))
+        };
+
+        // Next crate wanted by a stack without loose crates, the least buried one
+        let mut next: Option<(usize, usize, usize)> = None;
+        for &to in incomplete.iter().filter(|&&s| loose(s) == 0) {
+            let wanted = target.0[to][settled[to]];
+            for from in (0..stacks.0.len()).filter(|&s| s != to) {
+                let depth = stacks.0[from][settled[from]..]
+                    .iter()
+                    .rev()
+                    .position(|&c| c == wanted);
+                if let Some(depth) = depth.filter(|&d| next.is_none_or(|(best, ..)| d < best)) {
+                    next = Some((depth, from, to));
+                }
+            }
+        }
+
+        let mv = match next {
+            Some((0, from, to)) => placement(&stacks, target, crane, from, to),
+            Some((depth, from, to)) => Move {
+                times: depth,
+                from: from + 1,
+                to: spare([from, to])? + 1,
+            },
+            None => {
+                // Every stack waiting for crates has loose crates on top
+                let from = *incomplete.iter().min_by_key(|&&s| loose(s))?;
+                Move {
+                    times: loose(from),
+                    from: from + 1,
+                    to: spare([from, from])? + 1,
+                }
+            }
+        };
+        crane.execute(&mut stacks, &mv).ok()?;
+        moves.push(mv);
+    }
+}
+
+/// Fewest moves needed to go from `stacks` to `target`: every move takes
+/// crates from one stack and lays them on one other
+fn fewest_moves_left(stacks: &Stacks, target: &Stacks) -> usize {
+    let settled = settled(stacks, target);
+    let (mut to_empty, mut to_fill) = (0, 0);
+    for (s, stack) in stacks.0.iter().enumerate() {
+        to_empty += (settled[s] < stack.len()) as usize;
+        to_fill += (settled[s] < target.0[s].len()) as usize;
+    }
+    to_empty.max(to_fill)
+}
+
+/// The fewest moves arranging the `from` stacks as `target` with `crane`,
+/// looking at `limit` yards at most
+///
+/// `None` when the crates differ or no solution was found within the limit:
+/// the number of yards grows very fast with the crates, this is for small ones.
+///
+/// ```
+/// use day5::{shortest_moves, CrateMover9000, CrateMover9001, Stacks};
+///
+/// let from = Stacks(vec![vec!['Z', 'N'], vec!['M', 'C', 'D'], vec!['P']]);
+/// let target = Stacks(vec![vec!['C'], vec!['M'], vec!['P', 'D', 'N', 'Z']]);
+/// assert_eq!(shortest_moves(&from, &target, &CrateMover9000, 10_000).unwrap().len(), 3);
+/// assert_eq!(shortest_moves(&from, &target, &CrateMover9001, 10_000).unwrap().len(), 4);
+/// ```
+pub fn shortest_moves(
+    from: &Stacks,
+    target: &Stacks,
+    crane: &dyn Crane,
+    limit: usize,
+) -> Option<Vec<Move>> {
+    if !same_crates(from, target) {
+        return None;
+    }
+
+    let stacks = from.0.len();
+    let every_move = |yard: &Stacks| {
+        let mut next = Vec::new();
+        for from in 0..stacks {
+            for to in (0..stacks).filter(|&to| to != from) {
+                for times in 1..=yard.0[from].len() {
+                    let mut moved = yard.clone();
+                    let mv = Move {
+                        times,
+                        from: from + 1,
+                        to: to + 1,
+                    };
+                    if crane.execute(&mut moved, &mv).is_ok() {
+                        next.push((moved, 1));
+                    }
+                }
+            }
+        }
+        next
+    };
+
+    // The search stops on the target, or on any yard once over the limit
+    let mut searched = 0;
+    let paths = astar(
+        from.clone(),
+        every_move,
+        |yard| fewest_moves_left(yard, target),
+        |yard| {
+            searched += 1;
+            yard == target || searched > limit
+        },
+    );
+    if paths.goal.as_ref() != Some(target) {
+        return None;
+    }
+
+    // Each move empties a bit of one stack into another
+    let yards = paths.path(target)?;
+    let moves = yards
+        .windows(2)
+        .map(|pair| {
+            let change = |s: usize| pair[1].0[s].len() as isize - pair[0].0[s].len() as isize;
+            let from = (0..stacks).find(|&s| change(s) < 0).unwrap();
+            let to = (0..stacks).find(|&s| change(s) > 0).unwrap();
+            Move {
+                times: change(to) as usize,
+                from: from + 1,
+                to: to + 1,
+            }
+        })
+        .collect();
+    Some(moves)
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+
+    type Input = Procedure;
+    type Answer1 = String;
+    type Answer2 = String;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: include_str!("../example"),
+        part1: Some("CMZ"),
+        part2: Some("MCD"),
+    }];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let (drawing, moves) = input
+            .split_once("\n\n")
+            .ok_or_else(|| ParseError::missing(input, "expected the moves after an empty line"))?;
+
+        Ok(Procedure {
+            first_line: drawing.lines().count() + 2,
+            stacks: drawing
+                .parse()
+                .map_err(|e: ParseError| e.within(input, drawing))?,
+            moves: parse_lines(moves, Move::from_str).map_err(|e| e.within(input, moves))?,
+        })
+    }
+
+    fn part1(procedure: &Self::Input) -> Result<Self::Answer1> {
+        Ok(procedure.dry_run(&CrateMover9000)?.top_crates())
+    }
+
+    fn part2(procedure: &Self::Input) -> Result<Self::Answer2> {
+        Ok(procedure.dry_run(&CrateMover9001)?.top_crates())
+    }
+}
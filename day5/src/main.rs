@@ -1,111 +1,38 @@
-use std::{
-    fs::File,
-    io::{BufRead, BufReader},
-    str::FromStr,
-};
+use std::{env, fs};
 
-use anyhow::Result;
+use anyhow::{Context, Result};
+use aoc_common::Solution;
+use day5::{plan_moves, Crane, CrateMover9000, CrateMover9001, Day5, LimitedCrane};
 
-#[derive(Debug)]
-struct Move {
-    times: usize,
-    from: usize,
-    to: usize,
-}
-
-impl Move {
-    fn execute(&self, stacks: &mut [Vec<char>]) {
-        for _ in 0..self.times {
-            let c = stacks[self.from - 1].pop().unwrap();
-            stacks[self.to - 1].push(c);
+/// Solve the file given as argument, `input` by default
+fn main() -> Result<()> {
+    let path = env::args().nth(1).unwrap_or_else(|| "input".to_string());
+    let input = fs::read_to_string(&path).with_context(|| format!("Cannot open {}", path))?;
+    let procedure = Day5::parse(&input)?;
+
+    println!("{}", Day5::part1(&procedure)?);
+    println!("{}", Day5::part2(&procedure)?);
+
+    for capacity in [2, 3] {
+        let crane = LimitedCrane::new(capacity).unwrap();
+        match procedure.dry_run(&crane) {
+            Ok(stacks) => println!("{} (lifting {})", stacks.top_crates(), capacity),
+            Err(error) => println!("{} (lifting {})", error, capacity),
         }
     }
 
-    fn execute_all_at_once(&self, stacks: &mut [Vec<char>]) {
-        let mut crates = Vec::new();
-        for _ in 0..self.times {
-            crates.push(stacks[self.from - 1].pop().unwrap());
+    let cranes: [(&str, &dyn Crane); 2] = [("9000", &CrateMover9000), ("9001", &CrateMover9001)];
+    for (name, crane) in cranes {
+        let target = procedure.dry_run(crane)?;
+        if let Some(moves) = plan_moves(&procedure.stacks, &target, crane) {
+            println!(
+                "The CrateMover {} gets there in {} moves instead of {}",
+                name,
+                moves.len(),
+                procedure.moves.len()
+            );
         }
-        crates.reverse();
-        stacks[self.to - 1].append(&mut crates);
-    }
-}
-
-impl FromStr for Move {
-    type Err = anyhow::Error;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut inputs = s.split(' ').skip(1).step_by(2);
-        Ok(Self {
-            times: inputs.next().unwrap().parse()?,
-            from: inputs.next().unwrap().parse()?,
-            to: inputs.next().unwrap().parse()?,
-        })
-    }
-}
-
-fn main() -> Result<()> {
-    let moves = BufReader::new(File::open("input").unwrap())
-        .lines()
-        .map(|line| {
-            let line = line.unwrap();
-            Move::from_str(&line).unwrap()
-        });
-
-    // let mut stacks = [
-    //     vec!['Z', 'N'],
-    //     vec!['M', 'C', 'D'],
-    //     vec!['P'],
-    // ];
-
-    let mut stacks = [
-        vec!['W', 'M', 'F', 'L'],
-        vec!['B', 'Z', 'V', 'M', 'F'],
-        vec!['H', 'V', 'R', 'S', 'L', 'Q'],
-        vec!['F', 'S', 'V', 'Q', 'P', 'M', 'T', 'J'],
-        vec!['L', 'S', 'W'],
-        vec!['F', 'V', 'P', 'M', 'R', 'J', 'W'],
-        vec!['J', 'Q', 'C', 'P', 'N', 'R', 'F'],
-        vec!['V', 'H', 'P', 'S', 'Z', 'W', 'R', 'B'],
-        vec!['B', 'M', 'J', 'C', 'G', 'H', 'Z', 'W'],
-    ];
-
-    for m in moves {
-        m.execute(&mut stacks);
-    }
-
-    for mut stack in stacks {
-        print!("{}", stack.pop().unwrap());
-    }
-    println!("");
-
-    let moves = BufReader::new(File::open("input").unwrap())
-        .lines()
-        .map(|line| {
-            let line = line.unwrap();
-            Move::from_str(&line).unwrap()
-        });
-
-    let mut stacks = [
-        vec!['W', 'M', 'F', 'L'],
-        vec!['B', 'Z', 'V', 'M', 'F'],
-        vec!['H', 'V', 'R', 'S', 'L', 'Q'],
-        vec!['F', 'S', 'V', 'Q', 'P', 'M', 'T', 'J'],
-        vec!['L', 'S', 'W'],
-        vec!['F', 'V', 'P', 'M', 'R', 'J', 'W'],
-        vec!['J', 'Q', 'C', 'P', 'N', 'R', 'F'],
-        vec!['V', 'H', 'P', 'S', 'Z', 'W', 'R', 'B'],
-        vec!['B', 'M', 'J', 'C', 'G', 'H', 'Z', 'W'],
-    ];
-
-    for m in moves {
-        m.execute_all_at_once(&mut stacks);
-    }
-
-    for mut stack in stacks {
-        print!("{}", stack.pop().unwrap());
     }
-    println!("");
 
     Ok(())
 }
@@ -0,0 +1,311 @@
+// https://adventofcode.com/2022/day/6
+
+use std::{
+    collections::VecDeque,
+    io::{self, Read},
+};
+
+use anyhow::Error;
+use aoc_common::{Example, ParseError, Result, Solution};
+
+/// Finds the markers of a stream, where the last `size` bytes are all different
+///
+/// Keeps where each byte was last seen and the start of the run of different
+/// bytes ending with the last one, so every byte is constant work.
+///
+/// ```
+/// use day6::MarkerDetector;
+///
+/// let mut detector = MarkerDetector::new(4);
+/// let markers: Vec<usize> = detector.markers(b"mjqjpqmgbl").collect();
+/// assert_eq!(markers, [7, 8, 9, 10]);
+/// assert_eq!(detector.markers(b"llll").next(), None);
+/// assert_eq!(detector.position(), 14);
+/// ```
+#[derive(Debug, Clone)]
+pub struct MarkerDetector {
+    size: usize,
+    /// Bytes read so far
+    position: usize,
+    /// Position right after the last occurrence of each byte, 0 if never seen
+    last_seen: [usize; 256],
+    /// Start of the run of different bytes ending with the last one
+    run_start: usize,
+}
+
+impl MarkerDetector {
+    pub fn new(size: usize) -> Self {
+        MarkerDetector {
+            size,
+            position: 0,
+            last_seen: [0; 256],
+            run_start: 0,
+        }
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Bytes read so far
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Read one byte, returns the position after it if it ends a marker
+    pub fn push(&mut self, byte: u8) -> Option<usize> {
+        let seen = &mut self.last_seen[byte as usize];
+        self.run_start = self.run_start.max(*seen);
+        self.position += 1;
+        *seen = self.position;
+
+        (self.position - self.run_start >= self.size).then_some(self.position)
+    }
+
+    /// Positions after each marker ending in `bytes`, counted from the start
+    /// of the stream
+    pub fn markers<'a>(&'a mut self, bytes: &'a [u8]) -> impl Iterator<Item = usize> + 'a {
+        bytes.iter().filter_map(|&byte| self.push(byte))
+    }
+}
+
+/// Position after each marker of `size` bytes in `stream`
+pub fn find_markers(stream: &[u8], size: usize) -> impl Iterator<Item = usize> + '_ {
+    let mut detector = MarkerDetector::new(size);
+    stream.iter().filter_map(move |&byte| detector.push(byte))
+}
+
+/// Position after the first marker of `size` bytes in `stream`
+pub fn find_marker(stream: &[u8], size: usize) -> Option<usize> {
+    find_markers(stream, size).next()
+}
+
+/// Read a whole stream by chunks, `marker` is called with the position after
+/// each marker of `size` bytes, returns the length of the stream
+pub fn read_markers(
+    mut reader: impl Read,
+    size: usize,
+    mut marker: impl FnMut(usize),
+) -> io::Result<usize> {
+    let mut detector = MarkerDetector::new(size);
+    let mut buffer = vec![0; 64 * 1024];
+
+    loop {
+        let read = match reader.read(&mut buffer) {
+            Ok(0) => return Ok(detector.position()),
+            Ok(read) => read,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        };
+        detector.markers(&buffer[..read]).for_each(&mut marker);
+    }
+}
+
+pub const START_OF_PACKET: usize = 4;
+pub const START_OF_MESSAGE: usize = 14;
+
+/// What the framer found in a stream, positions are counted in bytes from the
+/// start of the stream
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Event {
+    /// Position after the start of packet marker
+    StartOfPacket(usize),
+    /// Position after the start of message marker
+    StartOfMessage(usize),
+    /// Bytes after the start of packet, split at the markers and at the
+    /// chunks of the stream, `start` is the position of the first one
+    Payload { start: usize, bytes: Vec<u8> },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Stage {
+    /// The bytes before the start of packet are dropped
+    Sync,
+    Packet,
+    Message,
+}
+
+/// Splits a stream fed by chunks into markers and payloads
+///
+/// ```
+/// use day6::{Event, Framer};
+///
+/// let mut framer = Framer::new();
+/// let mut events = Vec::new();
+/// for chunk in b"mjqjpqmgbljsphdztnvjfqwrcgsmlb".chunks(16) {
+///     framer.feed(chunk, |event| events.push(event));
+/// }
+///
+/// let payload = |start, bytes: &[u8]| Event::Payload { start, bytes: bytes.to_vec() };
+/// assert_eq!(events, [
+///     Event::StartOfPacket(7),
+///     payload(7, b"gbljsphdz"),
+///     payload(16, b"tnv"),
+///     Event::StartOfMessage(19),
+///     payload(19, b"jfqwrcgsmlb"),
+/// ]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Framer {
+    packet: MarkerDetector,
+    message: MarkerDetector,
+    stage: Stage,
+}
+
+impl Default for Framer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Framer {
+    /// Framer for the markers of the puzzle
+    pub fn new() -> Self {
+        Framer::with_sizes(START_OF_PACKET, START_OF_MESSAGE)
+    }
+
+    pub fn with_sizes(packet: usize, message: usize) -> Self {
+        Framer {
+            packet: MarkerDetector::new(packet),
+            message: MarkerDetector::new(message),
+            stage: Stage::Sync,
+        }
+    }
+
+    /// Bytes fed so far
+    pub fn position(&self) -> usize {
+        self.message.position()
+    }
+
+    /// Read the next chunk of the stream, `event` is called with what it holds
+    pub fn feed(&mut self, chunk: &[u8], mut event: impl FnMut(Event)) {
+        let start = self.position();
+        let mut segment = 0;
+        let payload = |event: &mut dyn FnMut(Event), from: usize, to: usize| {
+            if from < to {
+                event(Event::Payload {
+                    start: start + from,
+                    bytes: chunk[from..to].to_vec(),
+                });
+            }
+        };
+
+        for (i, &byte) in chunk.iter().enumerate() {
+            // The message marker is counted from the start of the stream too
+            let message = self.message.push(byte);
+            if self.stage == Stage::Sync {
+                if let Some(position) = self.packet.push(byte) {
+                    event(Event::StartOfPacket(position));
+                    self.stage = Stage::Packet;
+                    segment = i + 1;
+                }
+            }
+            if let (Stage::Packet, Some(position)) = (self.stage, message) {
+                payload(&mut event, segment, i + 1);
+                event(Event::StartOfMessage(position));
+                self.stage = Stage::Message;
+                segment = i + 1;
+            }
+        }
+
+        if self.stage != Stage::Sync {
+            payload(&mut event, segment, chunk.len());
+        }
+    }
+
+    /// Events of a whole stream, read by chunks
+    pub fn frames<R: Read>(self, reader: R) -> Frames<R> {
+        Frames {
+            reader,
+            framer: self,
+            buffer: vec![0; 64 * 1024],
+            events: VecDeque::new(),
+            done: false,
+        }
+    }
+}
+
+/// Iterator over the events of a stream, see [`Framer::frames`]
+#[derive(Debug)]
+pub struct Frames<R> {
+    reader: R,
+    framer: Framer,
+    buffer: Vec<u8>,
+    events: VecDeque<Event>,
+    done: bool,
+}
+
+impl<R: Read> Iterator for Frames<R> {
+    type Item = io::Result<Event>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.events.is_empty() && !self.done {
+            match self.reader.read(&mut self.buffer) {
+                Ok(0) => self.done = true,
+                Ok(read) => {
+                    let events = &mut self.events;
+                    self.framer
+                        .feed(&self.buffer[..read], |event| events.push_back(event));
+                }
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => (),
+                Err(e) => {
+                    self.done = true;
+                    return Some(Err(e));
+                }
+            }
+        }
+        self.events.pop_front().map(Ok)
+    }
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+
+    type Input = String;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    const EXAMPLES: &'static [Example] = &[
+        Example {
+            input: "mjqjpqmgbljsphdztnvjfqwrcgsmlb",
+            part1: Some("7"),
+            part2: Some("19"),
+        },
+        Example {
+            input: "bvwbjplbgvbhsrlpgdmjqwftvncz",
+            part1: Some("5"),
+            part2: Some("23"),
+        },
+        Example {
+            input: "nppdvjthqldpwncqszvftbrmjlhg",
+            part1: Some("6"),
+            part2: Some("23"),
+        },
+        Example {
+            input: "nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg",
+            part1: Some("10"),
+            part2: Some("29"),
+        },
+        Example {
+            input: "zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw",
+            part1: Some("11"),
+            part2: Some("26"),
+        },
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.to_owned())
+    }
+
+    fn part1(stream: &Self::Input) -> Result<Self::Answer1> {
+        find_marker(stream.as_bytes(), START_OF_PACKET)
+            .ok_or_else(|| Error::msg("There is no start of packet"))
+    }
+
+    fn part2(stream: &Self::Input) -> Result<Self::Answer2> {
+        find_marker(stream.as_bytes(), START_OF_MESSAGE)
+            .ok_or_else(|| Error::msg("There is no start of message"))
+    }
+}
@@ -1,78 +1,72 @@
 // https://adventofcode.com/2022/day/6
 
-use std::collections::VecDeque;
+use std::{
+    env, fs,
+    fs::File,
+    io::{self, Read},
+};
 
-struct StartDetector<const N: usize> {
-    state: VecDeque<char>,
+use anyhow::Context;
+use aoc_common::Solution;
+use day6::{read_markers, Day6, Event, Framer};
+
+/// Open the file given, or the standard input
+fn open(path: Option<&String>) -> anyhow::Result<Box<dyn Read>> {
+    Ok(match path {
+        Some(path) => Box::new(File::open(path).with_context(|| format!("Cannot open {}", path))?),
+        None => Box::new(io::stdin().lock()),
+    })
 }
 
-impl<const N: usize> StartDetector<N> {
-    fn new() -> Self {
-        Self {
-            state: VecDeque::new(),
+/// Without arguments, solve the puzzle input, else `day6 SIZE [FILE]` reports
+/// the markers of SIZE bytes and `day6 frames [FILE]` the packet and message
+/// of a file, or of the standard input
+fn main() -> anyhow::Result<()> {
+    let args: Vec<String> = env::args().skip(1).collect();
+    if args.first().is_some_and(|command| command == "frames") {
+        // Payload bytes before and after the start of message
+        let mut payload = [0, 0];
+        let mut in_message = false;
+        for event in Framer::new().frames(open(args.get(1))?) {
+            match event? {
+                Event::StartOfPacket(position) => println!("Start of packet: {}", position),
+                Event::StartOfMessage(position) => {
+                    println!("Start of message: {}", position);
+                    in_message = true;
+                }
+                Event::Payload { bytes, .. } => payload[in_message as usize] += bytes.len(),
+            }
         }
+        println!(
+            "{} bytes between the markers, {} bytes of message",
+            payload[0], payload[1]
+        );
+        return Ok(());
     }
 
-    fn detect(&mut self, character: char) -> bool {
-        self.state.push_front(character);
-
-        if self.state.len() == N + 1 {
-            self.state.pop_back();
+    if let Some(size) = args.first() {
+        let size: usize = size.parse().context("Expected a marker size")?;
+        let (mut markers, mut first) = (0, None);
+        let mut marker = |position| {
+            markers += 1;
+            first.get_or_insert(position);
+        };
+        let length = read_markers(open(args.get(1))?, size, &mut marker)?;
 
-            // Find if all the items in the queue are different
-            self.state
-                .iter()
-                .enumerate()
-                .map(|(i, value)| {
-                    self.state
-                        .iter()
-                        .enumerate()
-                        .filter(|(j, _)| *j != i)
-                        .fold(true, |sum, (_, other)| sum && *value != *other)
-                })
-                .fold(true, |sum, val| val && sum)
-        } else {
-            false
+        println!("{} bytes, {} markers of {} bytes", length, markers, size);
+        if let Some(first) = first {
+            println!("First marker ends at {}", first);
         }
+        return Ok(());
     }
-}
-
-fn find_marker<const N: usize>(stream: &str) -> usize {
-    let stream = stream.chars();
-    let mut detector = StartDetector::<N>::new();
-    stream.take_while(|c| detector.detect(*c) == false).count() + 1
-}
 
-const START_OF_PACKET: usize = 4;
-const START_OF_MESSAGE: usize = 14;
+    let stream = Day6::parse(&fs::read_to_string("input")?)?;
 
-#[rustfmt::skip]
-fn main() {
     // Part one, Start of packet
-
-    // Examples from the question
-    assert_eq!(find_marker::<START_OF_PACKET>("mjqjpqmgbljsphdztnvjfqwrcgsmlb"), 7);
-    assert_eq!(find_marker::<START_OF_PACKET>("bvwbjplbgvbhsrlpgdmjqwftvncz"), 5);
-    assert_eq!(find_marker::<START_OF_PACKET>("nppdvjthqldpwncqszvftbrmjlhg"), 6);
-    assert_eq!(find_marker::<START_OF_PACKET>("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg"), 10);
-    assert_eq!(find_marker::<START_OF_PACKET>("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw"), 11);
-
-    println!(
-        "Start of frame: {}",
-        find_marker::<START_OF_PACKET>(&std::fs::read_to_string("input").unwrap())
-    );
+    println!("Start of frame: {}", Day6::part1(&stream)?);
 
     // Part two, Start of message
+    println!("Start of message: {}", Day6::part2(&stream)?);
 
-    // Examples from the question
-    assert_eq!(find_marker::<START_OF_MESSAGE>("mjqjpqmgbljsphdztnvjfqwrcgsmlb"), 19);
-    assert_eq!(find_marker::<START_OF_MESSAGE>("bvwbjplbgvbhsrlpgdmjqwftvncz"), 23);
-    assert_eq!(find_marker::<START_OF_MESSAGE>("nppdvjthqldpwncqszvftbrmjlhg"), 23);
-    assert_eq!(find_marker::<START_OF_MESSAGE>("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg"), 29);
-    assert_eq!(find_marker::<START_OF_MESSAGE>("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw"), 26);
-
-    println!(
-        "Start of message: {}",
-        find_marker::<START_OF_MESSAGE>(&std::fs::read_to_string("input").unwrap())
-    );
+    Ok(())
 }
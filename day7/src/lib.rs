@@ -0,0 +1,402 @@
+// https://adventofcode.com/2022/day/7
+
+use anyhow::Error;
+use aoc_common::{next_token, parse_token, Example, ParseError, Result, Solution};
+use std::{collections::BTreeMap, str::FromStr};
+
+#[derive(Debug)]
+pub enum Line {
+    Cd(String),
+    Ls,
+    File { size: usize, name: String },
+    Dir { name: String },
+}
+
+impl FromStr for Line {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut elements = s.split(' ');
+        let first = next_token(s, &mut elements, "a command or a directory entry")?;
+
+        match first {
+            "$" => match next_token(s, &mut elements, "a command")? {
+                "cd" => {
+                    let name = next_token(s, &mut elements, "a directory name")?.into();
+                    Ok(Line::Cd(name))
+                }
+                "ls" => Ok(Line::Ls),
+                command => Err(ParseError::new(s, command, "expected `cd` or `ls`")),
+            },
+            "dir" => {
+                let name = next_token(s, &mut elements, "a directory name")?.into();
+                Ok(Self::Dir { name })
+            }
+            size => {
+                let size = parse_token(s, size)?;
+                let name = next_token(s, &mut elements, "a file name")?.into();
+                Ok(Self::File { size, name })
+            }
+        }
+    }
+}
+
+/// Index of a directory in its tree
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct DirId(usize);
+
+/// Index of a file in its tree
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct FileId(usize);
+
+/// What a name of a directory stands for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Entry {
+    Dir(DirId),
+    File(FileId),
+}
+
+#[derive(Debug, Clone)]
+pub struct Dir {
+    name: String,
+    parent: Option<DirId>,
+    entries: BTreeMap<String, Entry>,
+    /// Size of the files right in the directory
+    files_size: usize,
+    /// Size of every file below the directory, added up by
+    /// [`FsTreeBuilder::build`]
+    size: usize,
+}
+
+impl Dir {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// `None` for the root
+    pub fn parent(&self) -> Option<DirId> {
+        self.parent
+    }
+
+    /// Size of the files right in the directory
+    pub fn files_size(&self) -> usize {
+        self.files_size
+    }
+
+    /// Size of every file below the directory
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Entries of the directory, sorted by name
+    pub fn entries(&self) -> impl Iterator<Item = (&str, Entry)> + '_ {
+        self.entries
+            .iter()
+            .map(|(name, &entry)| (name.as_str(), entry))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct File {
+    name: String,
+    size: usize,
+    parent: DirId,
+}
+
+impl File {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    pub fn parent(&self) -> DirId {
+        self.parent
+    }
+}
+
+/// Filesystem with every directory and file in an arena, pointing at each
+/// other by index
+///
+/// Built by a [`FsTreeBuilder`], which adds up the sizes of the directories
+/// once every file is known, so the tree cannot change after.
+///
+/// ```
+/// use day7::{Entry, FsTree, FsTreeBuilder};
+///
+/// let mut builder = FsTreeBuilder::new();
+/// let a = builder.add_dir(FsTree::ROOT, "a").unwrap();
+/// let e = builder.add_dir(a, "e").unwrap();
+/// builder.add_file(e, "i", 584).unwrap();
+/// builder.add_file(a, "f", 29116).unwrap();
+/// let tree = builder.build();
+///
+/// assert_eq!(tree.lookup("/a/e"), Some(Entry::Dir(e)));
+/// assert_eq!(tree.path(e), "/a/e");
+/// assert_eq!(tree.dir(a).size(), 29700);
+/// assert_eq!(tree.dir(FsTree::ROOT).size(), 29700);
+/// assert_eq!(tree.files().count(), 2);
+/// ```
+#[derive(Debug, Clone)]
+pub struct FsTree {
+    dirs: Vec<Dir>,
+    files: Vec<File>,
+}
+
+impl FsTree {
+    pub const ROOT: DirId = DirId(0);
+
+    pub fn dir(&self, id: DirId) -> &Dir {
+        &self.dirs[id.0]
+    }
+
+    pub fn file(&self, id: FileId) -> &File {
+        &self.files[id.0]
+    }
+
+    /// Every directory, the root first, then in the order they were added
+    pub fn dirs(&self) -> impl Iterator<Item = (DirId, &Dir)> + '_ {
+        self.dirs.iter().enumerate().map(|(i, dir)| (DirId(i), dir))
+    }
+
+    /// Every file, in the order they were added
+    pub fn files(&self) -> impl Iterator<Item = (FileId, &File)> + '_ {
+        self.files
+            .iter()
+            .enumerate()
+            .map(|(i, file)| (FileId(i), file))
+    }
+
+    /// Entry named `name` in the directory `dir`
+    pub fn child(&self, dir: DirId, name: &str) -> Option<Entry> {
+        self.dir(dir).entries.get(name).copied()
+    }
+
+    /// Entry at `path`, from the root: `/a/b/c`, `..` goes up
+    pub fn lookup(&self, path: &str) -> Option<Entry> {
+        let mut entry = Entry::Dir(FsTree::ROOT);
+        for name in path.split('/').filter(|name| !name.is_empty()) {
+            let Entry::Dir(dir) = entry else {
+                return None;
+            };
+            entry = match name {
+                "." => entry,
+                ".." => Entry::Dir(self.dir(dir).parent?),
+                _ => self.child(dir, name)?,
+            };
+        }
+        Some(entry)
+    }
+
+    /// Path of `dir` from the root, `/a/b/c`
+    pub fn path(&self, dir: DirId) -> String {
+        let mut names = Vec::new();
+        let mut current = self.dir(dir);
+        while let Some(parent) = current.parent {
+            names.push(current.name.as_str());
+            current = self.dir(parent);
+        }
+        names.reverse();
+        format!("/{}", names.join("/"))
+    }
+}
+
+/// Adds the directories and files of a [`FsTree`], the sizes are only known
+/// once it is built
+#[derive(Debug, Clone)]
+pub struct FsTreeBuilder {
+    tree: FsTree,
+}
+
+impl Default for FsTreeBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FsTreeBuilder {
+    /// Tree with only the root directory
+    pub fn new() -> Self {
+        FsTreeBuilder {
+            tree: FsTree {
+                dirs: vec![Dir {
+                    name: "/".into(),
+                    parent: None,
+                    entries: BTreeMap::new(),
+                    files_size: 0,
+                    size: 0,
+                }],
+                files: Vec::new(),
+            },
+        }
+    }
+
+    /// Entry named `name` in the directory `dir`
+    pub fn child(&self, dir: DirId, name: &str) -> Option<Entry> {
+        self.tree.child(dir, name)
+    }
+
+    /// `None` for the root
+    pub fn parent(&self, dir: DirId) -> Option<DirId> {
+        self.tree.dir(dir).parent
+    }
+
+    /// Add the directory `name` in `parent`, returns it if it already exists
+    pub fn add_dir(&mut self, parent: DirId, name: &str) -> Result<DirId> {
+        match self.tree.child(parent, name) {
+            Some(Entry::Dir(dir)) => return Ok(dir),
+            Some(Entry::File(_)) => {
+                return Err(Error::msg(format!("{} is a file", name)));
+            }
+            None => (),
+        }
+
+        let dir = DirId(self.tree.dirs.len());
+        self.tree.dirs.push(Dir {
+            name: name.into(),
+            parent: Some(parent),
+            entries: BTreeMap::new(),
+            files_size: 0,
+            size: 0,
+        });
+        self.tree.dirs[parent.0]
+            .entries
+            .insert(name.into(), Entry::Dir(dir));
+        Ok(dir)
+    }
+
+    /// Add the file `name` in `parent`, a file listed again gets its new size
+    pub fn add_file(&mut self, parent: DirId, name: &str, size: usize) -> Result<FileId> {
+        let (file, old_size) = match self.tree.child(parent, name) {
+            Some(Entry::File(file)) => (file, self.tree.file(file).size),
+            Some(Entry::Dir(_)) => {
+                return Err(Error::msg(format!("{} is a directory", name)));
+            }
+            None => {
+                let file = FileId(self.tree.files.len());
+                self.tree.files.push(File {
+                    name: name.into(),
+                    size: 0,
+                    parent,
+                });
+                self.tree.dirs[parent.0]
+                    .entries
+                    .insert(name.into(), Entry::File(file));
+                (file, 0)
+            }
+        };
+
+        self.tree.files[file.0].size = size;
+        let files_size = &mut self.tree.dirs[parent.0].files_size;
+        *files_size = *files_size - old_size + size;
+        Ok(file)
+    }
+
+    /// Tree with the size of every directory, added up in one pass
+    ///
+    /// ```
+    /// use aoc_common::Solution;
+    /// use day7::{Day7, FsTree};
+    ///
+    /// // A file at each level of very deep directories
+    /// let mut transcript = String::from("$ cd /\n");
+    /// for _ in 0..100_000 {
+    ///     transcript += "$ ls\ndir d\n1 f\n$ cd d\n";
+    /// }
+    /// let tree = Day7::parse(&transcript).unwrap();
+    /// assert_eq!(tree.dir(FsTree::ROOT).size(), 100_000);
+    /// assert_eq!(Day7::part1(&tree).unwrap(), 100_000 * 100_001 / 2);
+    /// ```
+    pub fn build(mut self) -> FsTree {
+        let dirs = &mut self.tree.dirs;
+        dirs.iter_mut().for_each(|dir| dir.size = dir.files_size);
+        // Directories come after their parent, so they are complete when
+        // added to it
+        for i in (1..dirs.len()).rev() {
+            if let Some(DirId(parent)) = dirs[i].parent {
+                dirs[parent].size += dirs[i].size;
+            }
+        }
+        self.tree
+    }
+}
+
+pub const MAX_SIZE: usize = 100000;
+pub const AVAILABLE_SPACE: usize = 70000000;
+pub const UNUSED_SPACE_REQUIRED: usize = 30000000;
+pub const MAX_USED_SPACE: usize = AVAILABLE_SPACE - UNUSED_SPACE_REQUIRED;
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+
+    type Input = FsTree;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: include_str!("../example"),
+        part1: Some("95437"),
+        part2: Some("24933642"),
+    }];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut tree = FsTreeBuilder::new();
+        let mut current_dir = FsTree::ROOT;
+
+        for text in input.lines() {
+            let error = |message: &str| ParseError::new(input, text, message);
+            let line = Line::from_str(text).map_err(|e| e.within(input, text))?;
+
+            match line {
+                Line::Ls => (),
+                Line::Dir { name } => {
+                    tree.add_dir(current_dir, &name)
+                        .map_err(|e| error(&e.to_string()))?;
+                }
+                Line::File { size, name } => {
+                    tree.add_file(current_dir, &name, size)
+                        .map_err(|e| error(&e.to_string()))?;
+                }
+                Line::Cd(name) if name == "/" => current_dir = FsTree::ROOT,
+                Line::Cd(name) if name == ".." => {
+                    current_dir = tree
+                        .parent(current_dir)
+                        .ok_or_else(|| error("the root directory has no parent"))?;
+                }
+                Line::Cd(name) => match tree.child(current_dir, &name) {
+                    Some(Entry::Dir(dir)) => current_dir = dir,
+                    Some(Entry::File(_)) => return Err(error("not a directory")),
+                    None => return Err(error("no such directory, `ls` it first")),
+                },
+            }
+        }
+        Ok(tree.build())
+    }
+
+    fn part1(tree: &Self::Input) -> Result<Self::Answer1> {
+        let sum = tree
+            .dirs()
+            .map(|(_, dir)| dir.size())
+            .filter(|size| *size <= MAX_SIZE)
+            .sum();
+
+        Ok(sum)
+    }
+
+    fn part2(tree: &Self::Input) -> Result<Self::Answer2> {
+        let currently_used_space = tree.dir(FsTree::ROOT).size();
+        let to_free = currently_used_space.saturating_sub(MAX_USED_SPACE);
+
+        // The root always frees enough
+        Ok(tree
+            .dirs()
+            .map(|(_, dir)| dir.size())
+            .filter(|size| *size >= to_free)
+            .min()
+            .unwrap_or(0))
+    }
+}
@@ -0,0 +1,97 @@
+// https://adventofcode.com/2022/day/8
+
+use aoc_common::{DenseGrid, Example, ParseError, Point, Result, Solution};
+
+/// Walk the forest and return the number of visible trees and the best scenic score
+pub fn survey(forest: &DenseGrid<u8>) -> (usize, usize) {
+    let width = forest.width();
+    let height = forest.height();
+
+    let mut visible_trees = 2 * width + 2 * height - 4;
+    let mut best_scenic = 0;
+
+    for y in 1..height - 1 {
+        for x in 1..width - 1 {
+            let tree_height = &forest[Point::new(x as isize, y as isize)];
+            let line = forest.row(y);
+            let column: Vec<_> = forest.column(x).copied().collect();
+
+            let mut left = line.split_at(x).0.to_vec();
+            let mut right = line.split_at(x + 1).1.to_vec();
+            let mut top = column.split_at(y).0.to_vec();
+            let mut bottom = column.split_at(y + 1).1.to_vec();
+
+            let mut scenic_left = left.iter().rev().take_while(|v| tree_height > *v).count();
+            if scenic_left < left.len() {
+                scenic_left += 1;
+            }
+            let mut scenic_right = right.iter().take_while(|v| tree_height > *v).count();
+            if scenic_right < right.len() {
+                scenic_right += 1;
+            }
+            let mut scenic_top = top.iter().rev().take_while(|v| tree_height > *v).count();
+            if scenic_top < top.len() {
+                scenic_top += 1;
+            }
+            let mut scenic_bottom = bottom.iter().take_while(|v| tree_height > *v).count();
+            if scenic_bottom < bottom.len() {
+                scenic_bottom += 1;
+            }
+
+            let score = scenic_left * scenic_right * scenic_top * scenic_bottom;
+
+            if score > best_scenic {
+                best_scenic = score;
+            }
+            // println!("{} {} {} {} {}", scenic_top, scenic_left, scenic_bottom, scenic_right, score);
+
+            left.sort();
+            right.sort();
+            top.sort();
+            bottom.sort();
+
+            if tree_height > left.last().unwrap()
+                || tree_height > right.last().unwrap()
+                || tree_height > top.last().unwrap()
+                || tree_height > bottom.last().unwrap()
+            {
+                // println!("{} {} height {} is visible", x, y, tree_height);
+                visible_trees += 1;
+            }
+        }
+    }
+
+    (visible_trees, best_scenic)
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+
+    /// Heights of the trees
+    type Input = DenseGrid<u8>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: include_str!("../example"),
+        part1: Some("21"),
+        part2: Some("8"),
+    }];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        // The survey only knows how to walk a rectangular forest
+        DenseGrid::parse(input, "a tree height", |c| {
+            c.to_digit(10).map(|height| height as u8)
+        })
+    }
+
+    fn part1(forest: &Self::Input) -> Result<Self::Answer1> {
+        Ok(survey(forest).0)
+    }
+
+    fn part2(forest: &Self::Input) -> Result<Self::Answer2> {
+        Ok(survey(forest).1)
+    }
+}
@@ -0,0 +1,212 @@
+// https://adventofcode.com/2022/day/9
+
+use std::{collections::HashSet, iter, str::FromStr};
+
+use aoc_common::{
+    next_token, parse_lines, parse_token, Bounds, Example, Frame, ParseError, Part, Point, Render,
+    Result, Simulation, Solution,
+};
+
+/// Move `knot` until it touches `other`
+fn chase(knot: &mut Point, other: Point) {
+    while knot.chebyshev(other) >= 2 {
+        *knot += (other - *knot).signum();
+    }
+}
+
+pub struct Bridge<const N: usize> {
+    pub head: Point,
+    pub tails: [Point; N],
+}
+
+impl<const N: usize> Default for Bridge<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> Bridge<N> {
+    pub fn new() -> Self {
+        Bridge {
+            head: Point::ORIGIN,
+            tails: [Point::ORIGIN; N],
+        }
+    }
+
+    pub fn move_head(&mut self, direction: Direction) {
+        self.head += direction.into();
+
+        for t in 0..N {
+            match t {
+                0 => chase(&mut self.tails[0], self.head),
+                _ => {
+                    let tail_to_chase = self.tails[t - 1];
+                    chase(&mut self.tails[t], tail_to_chase);
+                }
+            }
+        }
+    }
+
+    pub fn tail(&self) -> Point {
+        *self.tails.last().unwrap()
+    }
+}
+
+#[derive(Debug, Hash, Clone, Copy, PartialEq)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl From<Direction> for Point {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::Up => Point::UP,
+            Direction::Down => Point::DOWN,
+            Direction::Left => Point::LEFT,
+            Direction::Right => Point::RIGHT,
+        }
+    }
+}
+
+impl FromStr for Direction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "U" => Ok(Self::Up),
+            "D" => Ok(Self::Down),
+            "L" => Ok(Self::Left),
+            "R" => Ok(Self::Right),
+            _ => Err(ParseError::new(s, s, "expected U, D, L or R")),
+        }
+    }
+}
+
+/// Rope pulled around by the moves, one step of the head at a time
+pub struct Rope<const N: usize> {
+    pub bridge: Bridge<N>,
+    moves: Vec<(Direction, usize)>,
+    /// Move being done, and the steps of it already done
+    next: usize,
+    taken: usize,
+    /// Locations visited by the tail
+    pub visited: HashSet<Point>,
+}
+
+impl<const N: usize> Rope<N> {
+    pub fn new(moves: &[(Direction, usize)]) -> Self {
+        let bridge = Bridge::<N>::new();
+        Rope {
+            visited: HashSet::from([bridge.tail()]),
+            bridge,
+            moves: moves.to_vec(),
+            next: 0,
+            taken: 0,
+        }
+    }
+}
+
+impl<const N: usize> Simulation for Rope<N> {
+    fn step(&mut self) -> bool {
+        while let Some(&(direction, n)) = self.moves.get(self.next) {
+            if self.taken == n {
+                self.next += 1;
+                self.taken = 0;
+                continue;
+            }
+
+            self.bridge.move_head(direction);
+            self.visited.insert(self.bridge.tail());
+            self.taken += 1;
+            return true;
+        }
+        false
+    }
+}
+
+impl<const N: usize> Render for Rope<N> {
+    fn render(&self) -> Frame {
+        let knots = iter::once(self.bridge.head).chain(self.bridge.tails);
+        let mut bounds = Bounds::new(Point::ORIGIN);
+        self.visited
+            .iter()
+            .copied()
+            .chain(knots)
+            .for_each(|p| bounds.extend(p));
+
+        Frame::draw(bounds, |p| {
+            let glyph = match p {
+                p if p == self.bridge.head => 'H',
+                p if self.bridge.tails.contains(&p) => {
+                    let knot = self.bridge.tails.iter().position(|t| *t == p).unwrap() + 1;
+                    char::from_digit(knot as u32 % 10, 10).unwrap()
+                }
+                p if self.visited.contains(&p) => '#',
+                Point::ORIGIN => 's',
+                _ => '.',
+            };
+            glyph.into()
+        })
+        .with_caption(format!("{} locations visited", self.visited.len()))
+    }
+}
+
+/// Pull the rope around and return it once all the moves are done
+pub fn simulate<const N: usize>(moves: &[(Direction, usize)]) -> Rope<N> {
+    let mut rope = Rope::<N>::new(moves);
+    while rope.step() {}
+    rope
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+
+    type Input = Vec<(Direction, usize)>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    const EXAMPLES: &'static [Example] = &[
+        Example {
+            input: include_str!("../example"),
+            part1: Some("13"),
+            part2: Some("1"),
+        },
+        Example {
+            input: include_str!("../example2"),
+            part1: None,
+            part2: Some("36"),
+        },
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input, |l| {
+            let mut elements = l.split(' ');
+            let direction = next_token(l, &mut elements, "a direction")?;
+            let steps = next_token(l, &mut elements, "a number of steps")?;
+            Ok((
+                Direction::from_str(direction).map_err(|e| e.within(l, direction))?,
+                parse_token(l, steps)?,
+            ))
+        })
+    }
+
+    fn part1(moves: &Self::Input) -> Result<Self::Answer1> {
+        Ok(simulate::<1>(moves).visited.len())
+    }
+
+    fn part2(moves: &Self::Input) -> Result<Self::Answer2> {
+        Ok(simulate::<9>(moves).visited.len())
+    }
+
+    fn simulation(moves: &Self::Input, part: Part) -> Option<Box<dyn Simulation>> {
+        match part {
+            Part::One => Some(Box::new(Rope::<1>::new(moves))),
+            Part::Two => Some(Box::new(Rope::<9>::new(moves))),
+        }
+    }
+}